@@ -1,30 +1,72 @@
+use aoc_common::{checked_sum, Overflow, ParseError, Token};
+
 #[derive(Debug)]
 pub struct Elf {
     items: Vec<i64>,
 }
 
 impl Elf {
-    pub fn new(item_strings: &[String]) -> Elf {
-        let parsed = item_strings
-            .iter()
-            .map(|x| x.parse::<i64>().unwrap())
-            .collect();
+    pub fn new<S: AsRef<str>>(item_strings: &[S]) -> Result<Elf, ParseError> {
+        let mut parsed = Vec::new();
+        for (index, item) in item_strings.iter().enumerate() {
+            let calories = Token::new(item.as_ref())
+                .parse_number::<i64>("a calorie count")
+                .map_err(|error| error.offset_lines(index))?;
+            parsed.push(calories);
+        }
+
+        Ok(Elf { items: parsed })
+    }
 
-        Elf { items: parsed }
+    pub fn get_total_calories(&self) -> Result<i64, Overflow> {
+        checked_sum(self.items.iter().copied(), "total calories")
     }
+}
 
-    pub fn get_total_calories(&self) -> i64 {
-        self.items.iter().sum()
+/// An elf with its total calories, numbered from 1 in input order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RankedElf {
+    pub elf: usize,
+    pub calories: i64,
+}
+
+impl RankedElf {
+    /// The JSON members describing the elf, for objects that add their own.
+    pub(crate) fn json_members(&self) -> String {
+        format!("\"elf\":{},\"calories\":{}", self.elf, self.calories)
     }
 }
 
+/// Every elf's total, in input order.
+pub(crate) fn totals(elves: &[Elf]) -> Result<Vec<RankedElf>, Overflow> {
+    let mut totals = Vec::with_capacity(elves.len());
+    for (index, elf) in elves.iter().enumerate() {
+        totals.push(RankedElf {
+            elf: index + 1,
+            calories: elf.get_total_calories()?,
+        });
+    }
+    Ok(totals)
+}
+
+/// One elf per total, each carrying a single item.
+#[cfg(test)]
+pub(crate) fn elves(totals: &[i64]) -> Vec<Elf> {
+    totals
+        .iter()
+        .map(|total| Elf::new(&[total.to_string()]).unwrap())
+        .collect()
+}
+
+#[cfg(test)]
 mod tests {
+    use aoc_common::ParseErrorKind;
 
     #[test]
     fn new_parses_string() {
         let input = vec!["1".to_owned(), "2".to_owned(), "3".to_owned()];
 
-        let result = super::Elf::new(&input);
+        let result = super::Elf::new(&input).unwrap();
 
         assert_eq!(result.items[0], 1);
         assert_eq!(result.items[1], 2);
@@ -34,8 +76,56 @@ mod tests {
     #[test]
     fn get_total_calories_adds_items() {
         let input = vec!["1".to_owned(), "2".to_owned(), "3".to_owned()];
-        let elf = super::Elf::new(&input);
+        let elf = super::Elf::new(&input).unwrap();
+
+        assert_eq!(elf.get_total_calories(), Ok(6));
+    }
+
+    #[test]
+    fn get_total_calories_when_at_maximum_returns_total() {
+        let input = vec![(i64::MAX - 1).to_string(), "1".to_owned()];
+        let elf = super::Elf::new(&input).unwrap();
+
+        assert_eq!(elf.get_total_calories(), Ok(i64::MAX));
+    }
+
+    #[test]
+    fn get_total_calories_when_past_maximum_returns_overflow() {
+        let input = vec![i64::MAX.to_string(), "1".to_owned()];
+        let elf = super::Elf::new(&input).unwrap();
+
+        let result = elf.get_total_calories().unwrap_err();
+
+        assert_eq!(result.what(), "total calories");
+    }
+
+    #[test]
+    fn totals_number_elves_from_one() {
+        let result = super::totals(&super::elves(&[5, 3])).unwrap();
+
+        assert_eq!(
+            result,
+            [
+                super::RankedElf {
+                    elf: 1,
+                    calories: 5
+                },
+                super::RankedElf {
+                    elf: 2,
+                    calories: 3
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn new_when_not_a_number_returns_error_on_item_line() {
+        let input = vec!["1", "2x", "3"];
+
+        let result = super::Elf::new(&input).unwrap_err();
 
-        assert_eq!(elf.get_total_calories(), 6);
+        assert_eq!(result.kind(), ParseErrorKind::InvalidNumber);
+        assert_eq!(result.line(), 2);
+        assert_eq!(result.found(), "2x");
     }
 }
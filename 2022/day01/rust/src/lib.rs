@@ -0,0 +1,129 @@
+mod elf;
+mod ranking;
+mod stats;
+mod tally;
+
+use std::fmt::Display;
+
+use aoc_common::{checked_sum, records, top_k, Error, Order, Overflow, ParseError, Solution};
+
+pub use crate::elf::{Elf, RankedElf};
+pub use crate::ranking::{Ranking, RankingMode, Standing};
+pub use crate::stats::{Bucket, CalorieStats};
+pub use crate::tally::CalorieTally;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<Elf>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let elves = Day01::elves(input.lines())?;
+        Ok(elves)
+    }
+
+    fn part1(elves: &Self::Input) -> Result<impl Display, Error> {
+        let total_calories = get_total_calories(elves)?;
+
+        Ok(total_calories.into_iter().max().unwrap_or(-1))
+    }
+
+    fn part2(elves: &Self::Input) -> Result<impl Display, Error> {
+        let total_calories = get_total_calories(elves)?;
+
+        let max_three = top_k(total_calories, 3, Order::Descending);
+        Ok(checked_sum(max_three, "top three calories")?)
+    }
+}
+
+impl Day01 {
+    /// Counts the calories line by line, keeping only what both parts need, for inputs too
+    /// large to hold every elf.
+    pub fn from_lines<S: AsRef<str>>(
+        lines: impl IntoIterator<Item = S>,
+    ) -> Result<CalorieTally, Error> {
+        let mut tally = CalorieTally::new();
+        for (index, line) in lines.into_iter().enumerate() {
+            tally
+                .push_line(line.as_ref())
+                .map_err(|error| error.offset_lines(index))?;
+        }
+        Ok(tally)
+    }
+
+    /// Reads one elf per blank-line-separated record, as the lines come in.
+    pub fn elves<S: AsRef<str>>(
+        lines: impl IntoIterator<Item = S>,
+    ) -> Result<Vec<Elf>, ParseError> {
+        records(lines)
+            .map(|record| record.parse(Elf::new))
+            .collect()
+    }
+}
+
+fn get_total_calories(elves: &[Elf]) -> Result<Vec<i64>, Overflow> {
+    elves.iter().map(Elf::get_total_calories).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = include_str!("../input_test.txt");
+
+    #[test]
+    fn elves_skips_repeated_blank_lines() {
+        let elves = Day01::elves(["", "1", "2", "", "", "3"]).unwrap();
+
+        let totals: Result<Vec<i64>, _> = elves.iter().map(Elf::get_total_calories).collect();
+        assert_eq!(totals.unwrap(), [3, 3]);
+    }
+
+    #[test]
+    fn from_lines_skips_repeated_blank_lines() {
+        let tally = Day01::from_lines(["", "1", "2", "", "", "3"]).unwrap();
+
+        assert_eq!(tally.top_three_total(), Ok(6));
+    }
+
+    #[test]
+    fn parse_when_not_a_number_reports_line() {
+        let result = Day01::parse("1\n\n2\nx\n");
+
+        assert!(matches!(result, Err(Error::Parse(error)) if error.line() == 4));
+    }
+
+    #[test]
+    fn part2_when_top_three_overflow_returns_error() {
+        let half = (i64::MAX / 2 + 1).to_string();
+        let elves = Day01::elves([half.as_str(), "", half.as_str(), "", "1"]).unwrap();
+
+        let result = Day01::part2(&elves).map(|answer| answer.to_string());
+
+        assert!(
+            matches!(result, Err(Error::Overflow(error)) if error.what() == "top three calories")
+        );
+    }
+
+    #[test]
+    fn from_lines_agrees_with_parts() {
+        let tally = Day01::from_lines(INPUT.lines()).unwrap();
+
+        assert_eq!(tally.max(), Some(24000));
+        assert_eq!(tally.top_three_total(), Ok(45000));
+    }
+
+    #[test]
+    fn part1_example() {
+        let elves = Day01::parse(INPUT).unwrap();
+
+        assert_eq!(Day01::part1(&elves).unwrap().to_string(), "24000");
+    }
+
+    #[test]
+    fn part2_example() {
+        let elves = Day01::parse(INPUT).unwrap();
+
+        assert_eq!(Day01::part2(&elves).unwrap().to_string(), "45000");
+    }
+}
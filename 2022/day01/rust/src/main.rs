@@ -1,25 +1,109 @@
-mod elf;
-mod util;
+use std::process::ExitCode;
 
-use crate::elf::Elf;
-use crate::util::{get_max_n_elements, read_all_lines};
+use aoc_common::{exit_code, DayArgs, Error, Format, Report};
+use clap::{Parser, ValueEnum};
+use day01::{CalorieStats, CalorieTally, Day01, Ranking, RankingMode};
 
-fn main() {
-    let lines = read_all_lines("./input.txt");
-    let split_lines: Vec<&[String]> = lines
-        .split(|line| line == "")
-        .filter(|group| !group.is_empty())
-        .collect::<Vec<&[String]>>();
+/// Counts the calories the elves carry.
+#[derive(Parser)]
+struct Args {
+    #[command(flatten)]
+    day: DayArgs,
 
-    let elves: Vec<Elf> = split_lines.iter().map(|input| Elf::new(input)).collect();
+    /// Print the distribution of the elves' total calories instead of the answers
+    #[arg(long, conflicts_with = "top")]
+    stats: bool,
 
-    let total_calories: Vec<i64> = elves.iter().map(|elf| elf.get_total_calories()).collect();
+    /// Print the elves ranked N or better, with their position in the input, instead of the
+    /// answers
+    #[arg(long, value_name = "N", value_parser = clap::value_parser!(u16).range(1..))]
+    top: Option<u16>,
 
-    let max_calories = total_calories.iter().max().unwrap_or(&-1);
+    /// How elves carrying the same total are ranked
+    #[arg(long, value_enum, default_value_t, requires = "top")]
+    ranking: RankingArg,
+}
+
+/// The command-line names of [`RankingMode`].
+#[derive(ValueEnum, Debug, Clone, Copy, Default)]
+enum RankingArg {
+    /// Tied elves share a rank and the next rank is skipped, like 1, 2, 2, 4
+    #[default]
+    Competition,
+    /// Tied elves share a rank and the next rank follows it, like 1, 2, 2, 3
+    Dense,
+}
+
+impl From<RankingArg> for RankingMode {
+    fn from(arg: RankingArg) -> Self {
+        match arg {
+            RankingArg::Competition => RankingMode::Competition,
+            RankingArg::Dense => RankingMode::Dense,
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    exit_code(run())
+}
+
+fn run() -> Result<(), Error> {
+    let args = Args::parse();
+    if args.stats {
+        return print_stats(&args.day);
+    }
+    if let Some(top) = args.top {
+        return print_top(&args.day, usize::from(top), args.ranking.into());
+    }
+
+    let args = args.day;
+    if args.format == Format::Json {
+        // The report hashes the whole input, so it is read at once.
+        let input = args.input.load()?;
+        println!("{}", Report::part1::<Day01>(2022, 1, &input)?.to_json());
+        println!("{}", Report::part2::<Day01>(2022, 1, &input)?.to_json());
+        return Ok(());
+    }
 
+    let mut tally = CalorieTally::new();
+    args.input
+        .lines()?
+        .for_each_line(|line| tally.push_line(line))?;
+
+    let max_calories = tally.max().unwrap_or(-1);
     println!("Maximum was {}", max_calories);
 
-    let max_three = get_max_n_elements(&total_calories, 3);
-    let total_max_three: i64 = max_three.iter().sum();
+    let total_max_three = tally.top_three_total()?;
     println!("maximum three was {}", total_max_three);
+
+    Ok(())
+}
+
+fn print_stats(args: &DayArgs) -> Result<(), Error> {
+    let elves = args.input.lines()?.parse(|lines| Day01::elves(lines))?;
+
+    match (CalorieStats::new(&elves)?, args.format) {
+        (Some(stats), Format::Json) => println!("{}", stats.to_json()),
+        (Some(stats), Format::Text) => print!("{stats}"),
+        (None, _) => println!("No elves in the input"),
+    }
+    Ok(())
+}
+
+fn print_top(args: &DayArgs, n: usize, mode: RankingMode) -> Result<(), Error> {
+    let elves = args.input.lines()?.parse(|lines| Day01::elves(lines))?;
+    let ranking = Ranking::new(&elves, mode)?;
+
+    if args.format == Format::Json {
+        println!("{}", ranking.to_json(n));
+        return Ok(());
+    }
+    println!("rank  elf  calories");
+    for standing in ranking.top(n) {
+        println!(
+            "{:>4}  {:>3}  {:>8}",
+            standing.rank, standing.elf.elf, standing.elf.calories
+        );
+    }
+    Ok(())
 }
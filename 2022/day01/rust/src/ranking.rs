@@ -0,0 +1,170 @@
+use std::cmp::Reverse;
+
+use aoc_common::Overflow;
+
+use crate::elf::{self, Elf, RankedElf};
+
+/// How elves carrying the same total are ranked.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RankingMode {
+    /// Tied elves share a rank and the next rank is skipped, like 1, 2, 2, 4
+    #[default]
+    Competition,
+    /// Tied elves share a rank and the next rank follows it, like 1, 2, 2, 3
+    Dense,
+}
+
+impl RankingMode {
+    /// Lowercase name, as used on the command line and in JSON.
+    pub fn name(&self) -> &'static str {
+        match self {
+            RankingMode::Competition => "competition",
+            RankingMode::Dense => "dense",
+        }
+    }
+}
+
+/// An elf's place in a [`Ranking`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Standing {
+    pub rank: usize,
+    pub elf: RankedElf,
+}
+
+/// Every elf ordered from the most calories to the fewest, ties in input order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ranking {
+    mode: RankingMode,
+    standings: Vec<Standing>,
+}
+
+impl Ranking {
+    pub fn new(elves: &[Elf], mode: RankingMode) -> Result<Ranking, Overflow> {
+        let mut totals = elf::totals(elves)?;
+        // Stable, so tied elves stay in input order.
+        totals.sort_by_key(|elf| Reverse(elf.calories));
+
+        let mut standings: Vec<Standing> = Vec::with_capacity(totals.len());
+        for (position, elf) in totals.into_iter().enumerate() {
+            let rank = match standings.last() {
+                Some(previous) if previous.elf.calories == elf.calories => previous.rank,
+                Some(previous) if mode == RankingMode::Dense => previous.rank + 1,
+                _ => position + 1,
+            };
+            standings.push(Standing { rank, elf });
+        }
+
+        Ok(Ranking { mode, standings })
+    }
+
+    pub fn mode(&self) -> RankingMode {
+        self.mode
+    }
+
+    pub fn standings(&self) -> &[Standing] {
+        &self.standings
+    }
+
+    /// The standings ranked `n` or better, so every elf tied at the cut is kept.
+    pub fn top(&self, n: usize) -> &[Standing] {
+        let end = self
+            .standings
+            .partition_point(|standing| standing.rank <= n);
+        &self.standings[..end]
+    }
+
+    /// Single-line JSON object holding the mode and the [`Ranking::top`] `n` standings.
+    pub fn to_json(&self, n: usize) -> String {
+        let standings: Vec<String> = self
+            .top(n)
+            .iter()
+            .map(|standing| {
+                format!(
+                    "{{\"rank\":{},{}}}",
+                    standing.rank,
+                    standing.elf.json_members()
+                )
+            })
+            .collect();
+        format!(
+            "{{\"ranking\":\"{}\",\"top\":[{}]}}",
+            self.mode.name(),
+            standings.join(",")
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::elf::elves;
+
+    fn ranks(ranking: &Ranking) -> Vec<(usize, usize)> {
+        ranking
+            .standings()
+            .iter()
+            .map(|standing| (standing.rank, standing.elf.elf))
+            .collect()
+    }
+
+    #[test]
+    fn new_orders_elves_by_calories() {
+        let ranking = Ranking::new(&elves(&[10, 30, 20]), RankingMode::Competition).unwrap();
+
+        assert_eq!(ranks(&ranking), [(1, 2), (2, 3), (3, 1)]);
+        assert_eq!(ranking.standings()[0].elf.calories, 30);
+    }
+
+    #[test]
+    fn competition_ranking_skips_after_ties() {
+        let ranking = Ranking::new(&elves(&[5, 9, 7, 9, 7, 1]), RankingMode::Competition).unwrap();
+
+        assert_eq!(
+            ranks(&ranking),
+            [(1, 2), (1, 4), (3, 3), (3, 5), (5, 1), (6, 6)]
+        );
+    }
+
+    #[test]
+    fn dense_ranking_doesnt_skip_after_ties() {
+        let ranking = Ranking::new(&elves(&[5, 9, 7, 9, 7, 1]), RankingMode::Dense).unwrap();
+
+        assert_eq!(
+            ranks(&ranking),
+            [(1, 2), (1, 4), (2, 3), (2, 5), (3, 1), (4, 6)]
+        );
+    }
+
+    #[test]
+    fn top_keeps_ties_at_the_cut() {
+        let elves = elves(&[5, 9, 7, 9, 7, 1]);
+
+        let competition = Ranking::new(&elves, RankingMode::Competition).unwrap();
+        let dense = Ranking::new(&elves, RankingMode::Dense).unwrap();
+
+        assert_eq!(competition.top(1).len(), 2);
+        assert_eq!(competition.top(2).len(), 2);
+        assert_eq!(competition.top(3).len(), 4);
+        assert_eq!(dense.top(2).len(), 4);
+        assert_eq!(dense.top(100).len(), 6);
+        assert!(dense.top(0).is_empty());
+    }
+
+    #[test]
+    fn to_json_lists_top_standings() {
+        let ranking = Ranking::new(&elves(&[3, 8, 8]), RankingMode::Dense).unwrap();
+
+        assert_eq!(
+            ranking.to_json(1),
+            "{\"ranking\":\"dense\",\"top\":[{\"rank\":1,\"elf\":2,\"calories\":8},\
+             {\"rank\":1,\"elf\":3,\"calories\":8}]}"
+        );
+    }
+
+    #[test]
+    fn new_when_total_overflows_returns_error() {
+        let elf = Elf::new(&[i64::MAX.to_string(), String::from("1")]).unwrap();
+
+        assert!(Ranking::new(&[elf], RankingMode::Dense).is_err());
+    }
+}
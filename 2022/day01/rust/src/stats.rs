@@ -0,0 +1,381 @@
+use std::fmt::{self, Display};
+
+use aoc_common::Overflow;
+
+use crate::elf::{self, Elf, RankedElf};
+
+/// Percentiles included in the report, nearest-rank.
+const PERCENTILES: [u8; 5] = [10, 25, 75, 90, 99];
+const HISTOGRAM_BUCKETS: usize = 10;
+const HISTOGRAM_WIDTH: usize = 40;
+
+/// Elves whose totals fall within `start..=end`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bucket {
+    pub start: i64,
+    pub end: i64,
+    pub count: usize,
+}
+
+/// Distribution of the elves' total calories.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CalorieStats {
+    pub count: usize,
+    pub mean: f64,
+    pub median: f64,
+    /// Pairs of percentile and the total at that percentile.
+    pub percentiles: Vec<(u8, i64)>,
+    /// Population standard deviation.
+    pub standard_deviation: f64,
+    /// The first elf with the fewest calories.
+    pub min: RankedElf,
+    /// The first elf with the most calories.
+    pub max: RankedElf,
+    pub histogram: Vec<Bucket>,
+}
+
+impl CalorieStats {
+    /// `None` when there are no elves.
+    pub fn new(elves: &[Elf]) -> Result<Option<CalorieStats>, Overflow> {
+        let totals = elf::totals(elves)?;
+        let (Some(&min), Some(&max)) = (
+            totals.iter().min_by_key(|elf| elf.calories),
+            totals.iter().rev().max_by_key(|elf| elf.calories),
+        ) else {
+            return Ok(None);
+        };
+
+        let mut sorted: Vec<i64> = totals.iter().map(|elf| elf.calories).collect();
+        sorted.sort_unstable();
+        let count = sorted.len();
+        let mean = sorted.iter().map(|&total| total as f64).sum::<f64>() / count as f64;
+        let variance = sorted
+            .iter()
+            .map(|&total| (total as f64 - mean).powi(2))
+            .sum::<f64>()
+            / count as f64;
+
+        Ok(Some(CalorieStats {
+            count,
+            mean,
+            median: median(&sorted),
+            percentiles: PERCENTILES
+                .iter()
+                .map(|&percentile| (percentile, nearest_rank(&sorted, percentile)))
+                .collect(),
+            standard_deviation: variance.sqrt(),
+            min,
+            max,
+            histogram: histogram(&sorted),
+        }))
+    }
+
+    /// Single-line JSON object.
+    pub fn to_json(&self) -> String {
+        let percentiles: Vec<String> = self
+            .percentiles
+            .iter()
+            .map(|(percentile, total)| format!("\"p{percentile}\":{total}"))
+            .collect();
+        let histogram: Vec<String> = self
+            .histogram
+            .iter()
+            .map(|bucket| {
+                format!(
+                    "{{\"start\":{},\"end\":{},\"count\":{}}}",
+                    bucket.start, bucket.end, bucket.count
+                )
+            })
+            .collect();
+        format!(
+            "{{\"count\":{},\"mean\":{},\"median\":{},\"percentiles\":{{{}}},\
+             \"standard_deviation\":{},\"min\":{},\"max\":{},\"histogram\":[{}]}}",
+            self.count,
+            self.mean,
+            self.median,
+            percentiles.join(","),
+            self.standard_deviation,
+            ranked_json(self.min),
+            ranked_json(self.max),
+            histogram.join(","),
+        )
+    }
+}
+
+impl Display for CalorieStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "elves               {}", self.count)?;
+        writeln!(f, "mean                {:.1}", self.mean)?;
+        writeln!(f, "median              {:.1}", self.median)?;
+        for (percentile, total) in &self.percentiles {
+            writeln!(f, "p{percentile:<18} {total}")?;
+        }
+        writeln!(f, "standard deviation  {:.1}", self.standard_deviation)?;
+        writeln!(
+            f,
+            "min                 {} (elf {})",
+            self.min.calories, self.min.elf
+        )?;
+        writeln!(
+            f,
+            "max                 {} (elf {})",
+            self.max.calories, self.max.elf
+        )?;
+
+        let largest = self
+            .histogram
+            .iter()
+            .map(|bucket| bucket.count)
+            .max()
+            .unwrap_or_default()
+            .max(1);
+        let digits = [self.min.calories, self.max.calories]
+            .iter()
+            .map(|calories| calories.to_string().len())
+            .max()
+            .unwrap_or_default();
+        for bucket in &self.histogram {
+            let bar = "#".repeat(bucket.count * HISTOGRAM_WIDTH / largest);
+            writeln!(
+                f,
+                "{:>digits$} - {:>digits$} | {bar} {}",
+                bucket.start, bucket.end, bucket.count
+            )?;
+        }
+        Ok(())
+    }
+}
+
+fn ranked_json(ranked: RankedElf) -> String {
+    format!("{{{}}}", ranked.json_members())
+}
+
+/// Middle value of `sorted`, the mean of the middle pair when the count is even.
+fn median(sorted: &[i64]) -> f64 {
+    let middle = sorted.len() / 2;
+    if sorted.len().is_multiple_of(2) {
+        (sorted[middle - 1] as f64 + sorted[middle] as f64) / 2.0
+    } else {
+        sorted[middle] as f64
+    }
+}
+
+/// Smallest value with at least `percentile` percent of `sorted` at or below it.
+fn nearest_rank(sorted: &[i64], percentile: u8) -> i64 {
+    let rank = (usize::from(percentile) * sorted.len()).div_ceil(100);
+    sorted[rank.max(1) - 1]
+}
+
+/// Splits `min..=max` of `sorted` into equal-width buckets, fewer when the range is narrow.
+fn histogram(sorted: &[i64]) -> Vec<Bucket> {
+    // In i128, as the span of the whole i64 range does not fit in an i64. Every bound lies
+    // within `first..=last`, so it fits back.
+    let (first, last) = (i128::from(sorted[0]), i128::from(sorted[sorted.len() - 1]));
+    let span = last - first + 1;
+    let width = (span + HISTOGRAM_BUCKETS as i128 - 1) / HISTOGRAM_BUCKETS as i128;
+    // Rounding the width up can leave the last buckets past `last`, so only as many are kept
+    // as it takes to reach it.
+    let buckets = (span + width - 1) / width;
+
+    let mut histogram: Vec<Bucket> = (0..buckets)
+        .map(|bucket| Bucket {
+            start: (first + bucket * width) as i64,
+            end: (first + (bucket + 1) * width - 1).min(last) as i64,
+            count: 0,
+        })
+        .collect();
+    for &total in sorted {
+        histogram[((i128::from(total) - first) / width) as usize].count += 1;
+    }
+    histogram
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::elf::elves;
+
+    #[test]
+    fn new_when_no_elves_returns_none() {
+        assert_eq!(CalorieStats::new(&[]), Ok(None));
+    }
+
+    #[test]
+    fn new_computes_summary() {
+        let stats = CalorieStats::new(&elves(&[6000, 4000, 11000, 24000, 10000]))
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(stats.count, 5);
+        assert_eq!(stats.mean, 11000.0);
+        assert_eq!(stats.median, 10000.0);
+        assert_eq!(
+            stats.min,
+            RankedElf {
+                elf: 2,
+                calories: 4000
+            }
+        );
+        assert_eq!(
+            stats.max,
+            RankedElf {
+                elf: 4,
+                calories: 24000
+            }
+        );
+        assert!((stats.standard_deviation - 6985.70).abs() < 0.01);
+    }
+
+    #[test]
+    fn new_when_elf_total_overflows_returns_error() {
+        let elf = Elf::new(&[i64::MAX.to_string(), String::from("1")]).unwrap();
+
+        assert!(CalorieStats::new(&[elf]).is_err());
+    }
+
+    #[test]
+    fn median_when_even_count_averages_middle_pair() {
+        let stats = CalorieStats::new(&elves(&[1, 2, 3, 10])).unwrap().unwrap();
+
+        assert_eq!(stats.median, 2.5);
+    }
+
+    #[test]
+    fn percentiles_use_nearest_rank() {
+        let totals: Vec<i64> = (1..=20).collect();
+
+        let stats = CalorieStats::new(&elves(&totals)).unwrap().unwrap();
+
+        assert_eq!(
+            stats.percentiles,
+            [(10, 2), (25, 5), (75, 15), (90, 18), (99, 20)]
+        );
+    }
+
+    #[test]
+    fn min_and_max_prefer_first_elf_on_ties() {
+        let stats = CalorieStats::new(&elves(&[5, 1, 5, 1])).unwrap().unwrap();
+
+        assert_eq!((stats.min.elf, stats.max.elf), (2, 1));
+    }
+
+    #[test]
+    fn histogram_counts_every_elf() {
+        let stats = CalorieStats::new(&elves(&[0, 5, 9, 10, 99]))
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(stats.histogram.len(), 10);
+        assert_eq!(
+            stats.histogram[0],
+            Bucket {
+                start: 0,
+                end: 9,
+                count: 3
+            }
+        );
+        assert_eq!(stats.histogram[9].end, 99);
+        let counted: usize = stats.histogram.iter().map(|bucket| bucket.count).sum();
+        assert_eq!(counted, 5);
+    }
+
+    #[test]
+    fn histogram_when_all_equal_has_one_bucket() {
+        let stats = CalorieStats::new(&elves(&[7, 7])).unwrap().unwrap();
+
+        assert_eq!(
+            stats.histogram,
+            [Bucket {
+                start: 7,
+                end: 7,
+                count: 2
+            }]
+        );
+    }
+
+    #[test]
+    fn histogram_when_range_not_a_multiple_of_buckets_keeps_only_needed_buckets() {
+        let totals: Vec<i64> = (0..=10).collect();
+
+        let stats = CalorieStats::new(&elves(&totals)).unwrap().unwrap();
+
+        assert_eq!(stats.histogram.len(), 6);
+        assert!(stats
+            .histogram
+            .iter()
+            .all(|bucket| bucket.start <= bucket.end));
+        assert_eq!(
+            stats.histogram[5],
+            Bucket {
+                start: 10,
+                end: 10,
+                count: 1
+            }
+        );
+        let counted: usize = stats.histogram.iter().map(|bucket| bucket.count).sum();
+        assert_eq!(counted, 11);
+    }
+
+    #[test]
+    fn histogram_spans_the_whole_i64_range() {
+        let stats = CalorieStats::new(&elves(&[i64::MIN, 0, i64::MAX]))
+            .unwrap()
+            .unwrap();
+
+        let histogram = &stats.histogram;
+        assert_eq!(histogram.len(), 10);
+        assert_eq!(histogram[0].start, i64::MIN);
+        assert_eq!(histogram[9].end, i64::MAX);
+        for pair in histogram.windows(2) {
+            assert_eq!(pair[0].end + 1, pair[1].start);
+        }
+        assert_eq!((histogram[0].count, histogram[9].count), (1, 1));
+        let counted: usize = histogram.iter().map(|bucket| bucket.count).sum();
+        assert_eq!(counted, 3);
+    }
+
+    #[test]
+    fn histogram_when_totals_reach_i64_max_counts_both_ends() {
+        let stats = CalorieStats::new(&elves(&[0, i64::MAX])).unwrap().unwrap();
+
+        assert_eq!(stats.histogram[0].start, 0);
+        assert_eq!(stats.histogram[0].count, 1);
+        assert_eq!(stats.histogram[9].end, i64::MAX);
+        assert_eq!(stats.histogram[9].count, 1);
+    }
+
+    #[test]
+    fn to_json_lists_every_field() {
+        let stats = CalorieStats::new(&elves(&[1, 3])).unwrap().unwrap();
+
+        assert_eq!(
+            stats.to_json(),
+            "{\"count\":2,\"mean\":2,\"median\":2,\
+             \"percentiles\":{\"p10\":1,\"p25\":1,\"p75\":3,\"p90\":3,\"p99\":3},\
+             \"standard_deviation\":1,\"min\":{\"elf\":1,\"calories\":1},\
+             \"max\":{\"elf\":2,\"calories\":3},\
+             \"histogram\":[{\"start\":1,\"end\":1,\"count\":1},{\"start\":2,\"end\":2,\"count\":0},\
+             {\"start\":3,\"end\":3,\"count\":1}]}"
+        );
+    }
+
+    #[test]
+    fn display_draws_histogram_bars() {
+        let stats = CalorieStats::new(&elves(&[1, 3, 3])).unwrap().unwrap();
+
+        let text = stats.to_string();
+
+        assert!(text.contains("1 - 1 | #################### 1\n"));
+        assert!(text.contains("3 - 3 | ######################################## 2\n"));
+    }
+
+    #[test]
+    fn display_aligns_labels_to_the_widest_total() {
+        let stats = CalorieStats::new(&elves(&[-1000, 5])).unwrap().unwrap();
+
+        let text = stats.to_string();
+
+        assert!(text.contains("\n-1000 -  -900 | "));
+        assert!(text.contains("\n  -91 -     5 | "));
+    }
+}
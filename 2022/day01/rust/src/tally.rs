@@ -0,0 +1,127 @@
+use aoc_common::{checked_sum, Error, Order, Overflow, Token, TopK};
+
+/// The answers to both parts, updated one input line at a time so no elf is kept once counted.
+#[derive(Debug, Clone)]
+pub struct CalorieTally {
+    /// Total of the elf being read, `None` between elves.
+    current: Option<i64>,
+    /// The largest totals of the elves already read.
+    top_three: TopK<i64>,
+}
+
+impl CalorieTally {
+    pub fn new() -> Self {
+        CalorieTally {
+            current: None,
+            top_three: TopK::new(3, Order::Descending),
+        }
+    }
+
+    /// Adds a line of input, either a calorie count or a blank line ending the current elf.
+    pub fn push_line(&mut self, line: &str) -> Result<(), Error> {
+        if line.trim().is_empty() {
+            if let Some(total) = self.current.take() {
+                self.top_three.push(total);
+            }
+            return Ok(());
+        }
+
+        let calories = Token::new(line).parse_number::<i64>("a calorie count")?;
+        let total = self
+            .current
+            .unwrap_or(0)
+            .checked_add(calories)
+            .ok_or_else(|| Overflow::new::<i64>("total calories"))?;
+        self.current = Some(total);
+        Ok(())
+    }
+
+    /// Total of the elf carrying the most, `None` when there are no elves.
+    pub fn max(&self) -> Option<i64> {
+        self.top_three().first().copied()
+    }
+
+    /// Total of the three elves carrying the most, or of every elf when there are fewer.
+    pub fn top_three_total(&self) -> Result<i64, Overflow> {
+        checked_sum(self.top_three(), "top three calories")
+    }
+
+    /// The largest totals, largest first, counting the elf still being read.
+    fn top_three(&self) -> Vec<i64> {
+        let mut top_three = self.top_three.clone();
+        top_three.extend(self.current);
+        top_three.into_sorted_vec()
+    }
+}
+
+impl Default for CalorieTally {
+    fn default() -> Self {
+        CalorieTally::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tally(lines: &[&str]) -> CalorieTally {
+        let mut tally = CalorieTally::new();
+        for line in lines {
+            tally.push_line(line).unwrap();
+        }
+        tally
+    }
+
+    #[test]
+    fn max_counts_the_elf_still_being_read() {
+        let tally = tally(&["1", "", "2", "3"]);
+
+        assert_eq!(tally.max(), Some(5));
+        assert_eq!(tally.top_three_total(), Ok(6));
+    }
+
+    #[test]
+    fn max_when_no_elves_returns_none() {
+        let tally = tally(&["", ""]);
+
+        assert_eq!(tally.max(), None);
+        assert_eq!(tally.top_three_total(), Ok(0));
+    }
+
+    #[test]
+    fn top_three_keep_equal_totals() {
+        let tally = tally(&["5", "", "9", "", "5", "", "9", "", "1"]);
+
+        assert_eq!(tally.top_three_total(), Ok(23));
+    }
+
+    #[test]
+    fn many_elves_keep_only_three_totals() {
+        let mut tally = CalorieTally::new();
+
+        for elf in 1..=100_000 {
+            tally.push_line(&elf.to_string()).unwrap();
+            tally.push_line("").unwrap();
+        }
+
+        assert_eq!(tally.top_three.len(), 3);
+        assert_eq!(tally.top_three(), [100_000, 99_999, 99_998]);
+        assert_eq!(tally.current, None);
+    }
+
+    #[test]
+    fn push_line_when_elf_total_overflows_returns_error() {
+        let mut tally = tally(&[&i64::MAX.to_string()]);
+
+        let result = tally.push_line("1");
+
+        assert!(matches!(result, Err(Error::Overflow(error)) if error.what() == "total calories"));
+    }
+
+    #[test]
+    fn push_line_when_not_a_number_returns_parse_error() {
+        let result = CalorieTally::new().push_line("12a");
+
+        assert!(matches!(result, Err(Error::Parse(error)) if error.line() == 1));
+    }
+}
@@ -1,5 +0,0 @@
-mod file_util;
-mod vector_util;
-
-pub use file_util::read_all_lines;
-pub use vector_util::get_max_n_elements;
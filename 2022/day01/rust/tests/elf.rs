@@ -0,0 +1,30 @@
+use day01::{Day01, Elf, Ranking, RankingMode};
+
+#[test]
+fn elf_totals_its_items() {
+    let elf = Elf::new(&["1000", "2000", "3000"]).unwrap();
+
+    assert_eq!(elf.get_total_calories(), Ok(6000));
+}
+
+#[test]
+fn elves_reads_one_elf_per_group() {
+    let elves = Day01::elves(["1000", "2000", "", "4000"]).unwrap();
+
+    let totals: Result<Vec<i64>, _> = elves.iter().map(Elf::get_total_calories).collect();
+    assert_eq!(totals.unwrap(), [3000, 4000]);
+}
+
+#[test]
+fn ranking_names_the_elves_carrying_the_most() {
+    let elves = Day01::elves(["1000", "", "3000", "", "2000", "1000", "", "500"]).unwrap();
+
+    let ranking = Ranking::new(&elves, RankingMode::Competition).unwrap();
+
+    let top: Vec<(usize, usize, i64)> = ranking
+        .top(1)
+        .iter()
+        .map(|standing| (standing.rank, standing.elf.elf, standing.elf.calories))
+        .collect();
+    assert_eq!(top, [(1, 2, 3000), (1, 3, 3000)]);
+}
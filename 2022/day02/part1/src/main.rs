@@ -1,17 +1,30 @@
-use game::Game;
-use util::read_all_lines;
+use std::process::ExitCode;
 
-mod game;
-mod matchup;
-mod opponent;
-mod player;
-mod util;
+use aoc_common::{exit_code, DayArgs, Error, Format, Report};
+use clap::Parser;
+use day02_part1::{Day02, Game};
 
-fn main() {
-    let lines = read_all_lines("./input.txt");
+fn main() -> ExitCode {
+    exit_code(run())
+}
+
+fn run() -> Result<(), Error> {
+    let args = DayArgs::parse();
+    if args.format == Format::Json {
+        // The report hashes the whole input, so it is read at once.
+        let input = args.input.load()?;
+        println!("{}", Report::part1::<Day02>(2022, 2, &input)?.to_json());
+        return Ok(());
+    }
 
-    let game = Game::new(lines);
-    let score = game.get_score();
+    let mut game = Game::default();
+    args.input
+        .lines()?
+        .for_each_line(|line| game.play_line(line))?;
+
+    let score = game.get_score()?;
 
     println!("The total score was {}", score);
+
+    Ok(())
 }
@@ -1,3 +1,5 @@
+use aoc_common::{ParseError, Tokens};
+
 use crate::{
     opponent::{Choice as OpponentChoice, Opponent},
     player::{Choice as PlayerChoice, Player},
@@ -10,12 +12,28 @@ pub struct Matchup {
 }
 
 impl Matchup {
-    pub fn new(matchup_string: &str) -> Matchup {
-        let strings: Vec<&str> = matchup_string.split(" ").collect();
-        let opponent = Opponent::new(strings[0]);
-        let player = Player::new(strings[1]);
+    pub fn new(matchup_string: &str) -> Result<Matchup, ParseError> {
+        let mut tokens = Tokens::new(matchup_string, ' ');
+
+        let opponent_token = tokens.expect("an opponent choice")?;
+        let opponent = Opponent::new(opponent_token.text)
+            .map_err(|error| error.offset_columns(opponent_token.span.start))?;
+
+        let player_token = tokens.expect("a player choice")?;
+        let player = Player::new(player_token.text)
+            .map_err(|error| error.offset_columns(player_token.span.start))?;
+
+        tokens.finish("end of line")?;
+
+        Ok(Matchup { player, opponent })
+    }
+
+    pub fn get_opponent(&self) -> &Opponent {
+        &self.opponent
+    }
 
-        Matchup { player, opponent }
+    pub fn get_player(&self) -> &Player {
+        &self.player
     }
 
     pub fn get_score(&self) -> i32 {
@@ -68,6 +86,7 @@ impl Outcome {
     }
 }
 
+#[cfg(test)]
 mod outcome_tests {
     #[test]
     fn get_score_when_win_6() {
@@ -91,15 +110,33 @@ mod outcome_tests {
     }
 }
 
+#[cfg(test)]
 mod matchup_tests {
+    use aoc_common::ParseErrorKind;
 
     #[test]
     fn creation_parses_string() {
         let input = "A X";
 
-        let result = super::Matchup::new(input);
+        let result = super::Matchup::new(input).unwrap();
 
         assert_eq!(result.player.get_choice(), &super::PlayerChoice::ROCK);
         assert_eq!(result.opponent.get_choice(), &super::OpponentChoice::ROCK);
     }
+
+    #[test]
+    fn creation_when_invalid_player_reports_column() {
+        let result = super::Matchup::new("A Q").unwrap_err();
+
+        assert_eq!(result.span(), 2..3);
+        assert_eq!(result.found(), "Q");
+    }
+
+    #[test]
+    fn creation_when_player_missing_returns_missing_token() {
+        let result = super::Matchup::new("A").unwrap_err();
+
+        assert_eq!(result.kind(), ParseErrorKind::MissingToken);
+        assert_eq!(result.expected(), "a player choice");
+    }
 }
@@ -1,12 +1,14 @@
+use aoc_common::ParseError;
+
 #[derive(Debug)]
-pub(crate) struct Opponent {
+pub struct Opponent {
     choice: Choice,
 }
 
 impl Opponent {
-    pub fn new(value: &str) -> Opponent {
-        let choice = Choice::from_value(value);
-        Opponent { choice }
+    pub fn new(value: &str) -> Result<Opponent, ParseError> {
+        let choice = Choice::from_value(value)?;
+        Ok(Opponent { choice })
     }
 
     pub fn get_choice(&self) -> &Choice {
@@ -22,37 +24,45 @@ pub enum Choice {
 }
 
 impl Choice {
-    fn from_value(value: &str) -> Choice {
+    fn from_value(value: &str) -> Result<Choice, ParseError> {
         match value {
-            "A" => Choice::ROCK,
-            "B" => Choice::PAPER,
-            "C" => Choice::SCISSORS,
-            _ => panic!("Invalid enum value {}", value),
+            "A" => Ok(Choice::ROCK),
+            "B" => Ok(Choice::PAPER),
+            "C" => Ok(Choice::SCISSORS),
+            _ => Err(ParseError::unexpected(
+                0..value.len(),
+                "one of A, B or C",
+                value,
+            )),
         }
     }
 }
 
+#[cfg(test)]
 mod tests {
+    use aoc_common::ParseErrorKind;
 
     #[test]
     fn choice_from_value_when_x_rock() {
-        assert_eq!(super::Choice::from_value("A"), super::Choice::ROCK);
+        assert_eq!(super::Choice::from_value("A"), Ok(super::Choice::ROCK));
     }
 
     #[test]
     fn choice_from_value_when_y_paper() {
-        assert_eq!(super::Choice::from_value("B"), super::Choice::PAPER);
+        assert_eq!(super::Choice::from_value("B"), Ok(super::Choice::PAPER));
     }
 
     #[test]
     fn choice_from_value_when_z_scissors() {
-        assert_eq!(super::Choice::from_value("C"), super::Choice::SCISSORS);
+        assert_eq!(super::Choice::from_value("C"), Ok(super::Choice::SCISSORS));
     }
 
     #[test]
-    #[should_panic(expected = "Invalid enum value X")]
+    fn choice_from_value_when_unknown_returns_error() {
+        let result = super::Choice::from_value("X").unwrap_err();
 
-    fn choice_from_value_when_unknown_panic() {
-        super::Choice::from_value("X");
+        assert_eq!(result.kind(), ParseErrorKind::UnexpectedToken);
+        assert_eq!(result.expected(), "one of A, B or C");
+        assert_eq!(result.found(), "X");
     }
 }
@@ -1,12 +1,14 @@
+use aoc_common::ParseError;
+
 #[derive(Debug)]
-pub(crate) struct Player {
+pub struct Player {
     choice: Choice,
 }
 
 impl Player {
-    pub fn new(value: &str) -> Player {
-        let choice = Choice::from_value(value);
-        Player { choice }
+    pub fn new(value: &str) -> Result<Player, ParseError> {
+        let choice = Choice::from_value(value)?;
+        Ok(Player { choice })
     }
 
     pub fn get_choice(&self) -> &Choice {
@@ -26,12 +28,16 @@ pub enum Choice {
 }
 
 impl Choice {
-    fn from_value(value: &str) -> Choice {
+    fn from_value(value: &str) -> Result<Choice, ParseError> {
         match value {
-            "X" => Choice::ROCK,
-            "Y" => Choice::PAPER,
-            "Z" => Choice::SCISSORS,
-            _ => panic!("Invalid enum value {}", value),
+            "X" => Ok(Choice::ROCK),
+            "Y" => Ok(Choice::PAPER),
+            "Z" => Ok(Choice::SCISSORS),
+            _ => Err(ParseError::unexpected(
+                0..value.len(),
+                "one of X, Y or Z",
+                value,
+            )),
         }
     }
 
@@ -44,28 +50,32 @@ impl Choice {
     }
 }
 
+#[cfg(test)]
 mod tests {
+    use aoc_common::ParseErrorKind;
 
     #[test]
     fn choice_from_value_when_x_rock() {
-        assert_eq!(super::Choice::from_value("X"), super::Choice::ROCK);
+        assert_eq!(super::Choice::from_value("X"), Ok(super::Choice::ROCK));
     }
 
     #[test]
     fn choice_from_value_when_y_paper() {
-        assert_eq!(super::Choice::from_value("Y"), super::Choice::PAPER);
+        assert_eq!(super::Choice::from_value("Y"), Ok(super::Choice::PAPER));
     }
 
     #[test]
     fn choice_from_value_when_z_scissors() {
-        assert_eq!(super::Choice::from_value("Z"), super::Choice::SCISSORS);
+        assert_eq!(super::Choice::from_value("Z"), Ok(super::Choice::SCISSORS));
     }
 
     #[test]
-    #[should_panic(expected = "Invalid enum value A")]
+    fn choice_from_value_when_unknown_returns_error() {
+        let result = super::Choice::from_value("A").unwrap_err();
 
-    fn choice_from_value_when_unknown_panic() {
-        super::Choice::from_value("A");
+        assert_eq!(result.kind(), ParseErrorKind::UnexpectedToken);
+        assert_eq!(result.expected(), "one of X, Y or Z");
+        assert_eq!(result.found(), "A");
     }
 
     #[test]
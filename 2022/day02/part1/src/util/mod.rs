@@ -1,3 +0,0 @@
-mod file_util;
-
-pub use file_util::read_all_lines;
@@ -1,24 +1,66 @@
+use aoc_common::{Overflow, ParseError};
+
 use crate::matchup::Matchup;
 
+/// Running score of a strategy guide, which keeps no round once it is scored.
+#[derive(Debug, Default)]
 pub struct Game {
-    rounds: Vec<Matchup>,
+    /// Wide enough that no number of rounds a `usize` can count overflows it.
+    score: i128,
 }
 
 impl Game {
-    pub fn new(string_lines: Vec<String>) -> Game {
-        let mut result = Vec::new();
-        for line in string_lines {
-            result.push(Matchup::new(&line));
+    /// Scores one round per line, consuming the lines as they come.
+    pub fn new<S: AsRef<str>>(lines: impl IntoIterator<Item = S>) -> Result<Game, ParseError> {
+        let mut game = Game::default();
+        for (index, line) in lines.into_iter().enumerate() {
+            game.play_line(line.as_ref())
+                .map_err(|error| error.offset_lines(index))?;
         }
 
-        Game { rounds: result }
+        Ok(game)
     }
 
-    pub fn get_score(&self) -> i32 {
-        let mut result = 0;
-        for matchup in &self.rounds {
-            result += matchup.get_score();
-        }
-        result
+    /// Scores the round on `line`.
+    pub fn play_line(&mut self, line: &str) -> Result<(), ParseError> {
+        self.play(&Matchup::new(line)?);
+        Ok(())
+    }
+
+    pub fn play(&mut self, matchup: &Matchup) {
+        self.score += i128::from(matchup.get_score());
+    }
+
+    /// Sum of every round's score, failing when it does not fit in an `i64`.
+    pub fn get_score(&self) -> Result<i64, Overflow> {
+        i64::try_from(self.score).map_err(|_| Overflow::new::<i64>("total score"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn get_score_adds_up_to_i64_max() {
+        let mut game = Game {
+            score: i128::from(i64::MAX) - 4,
+        };
+
+        game.play_line("A Y").unwrap();
+
+        assert_eq!(game.get_score(), Ok(i64::MAX));
+    }
+
+    #[test]
+    fn get_score_when_past_i64_max_returns_overflow() {
+        let mut game = Game {
+            score: i128::from(i64::MAX),
+        };
+
+        game.play_line("A Y").unwrap();
+
+        let result = game.get_score().unwrap_err();
+        assert_eq!(result.to_string(), "total score overflowed i64");
     }
 }
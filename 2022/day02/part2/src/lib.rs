@@ -0,0 +1,55 @@
+mod game;
+mod matchup;
+mod opponent;
+mod player;
+
+use std::fmt::Display;
+
+use aoc_common::{parse_lines, Error, ParseError, Solution};
+
+pub use crate::game::Game;
+pub use crate::matchup::Matchup;
+pub use crate::opponent::{Choice as OpponentChoice, Opponent};
+pub use crate::player::{Choice as PlayerChoice, Player};
+
+pub struct Day02;
+
+impl Day02 {
+    pub fn from_lines<S: AsRef<str>>(
+        lines: impl IntoIterator<Item = S>,
+    ) -> Result<Game, ParseError> {
+        Game::new(lines)
+    }
+}
+
+impl Solution for Day02 {
+    type Input = Vec<Matchup>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let rounds = parse_lines(input, Matchup::new)?;
+        Ok(rounds)
+    }
+
+    fn part2(rounds: &Self::Input) -> Result<impl Display, Error> {
+        let mut game = Game::default();
+        for matchup in rounds {
+            game.play(matchup);
+        }
+
+        Ok(game.get_score()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = include_str!("../input_test.txt");
+
+    #[test]
+    fn part2_example() {
+        let rounds = Day02::parse(INPUT).unwrap();
+
+        assert_eq!(Day02::part2(&rounds).unwrap().to_string(), "12");
+    }
+}
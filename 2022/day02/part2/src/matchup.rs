@@ -1,3 +1,5 @@
+use aoc_common::{ParseError, Tokens};
+
 use crate::{opponent::Opponent, player::Player};
 
 #[derive(Debug)]
@@ -7,12 +9,28 @@ pub struct Matchup {
 }
 
 impl Matchup {
-    pub fn new(matchup_string: &str) -> Matchup {
-        let strings: Vec<&str> = matchup_string.split(" ").collect();
-        let opponent = Opponent::new(strings[0]);
-        let player = Player::new(strings[1], opponent.get_choice());
+    pub fn new(matchup_string: &str) -> Result<Matchup, ParseError> {
+        let mut tokens = Tokens::new(matchup_string, ' ');
+
+        let opponent_token = tokens.expect("an opponent choice")?;
+        let opponent = Opponent::new(opponent_token.text)
+            .map_err(|error| error.offset_columns(opponent_token.span.start))?;
+
+        let outcome_token = tokens.expect("an outcome")?;
+        let player = Player::new(outcome_token.text, opponent.get_choice())
+            .map_err(|error| error.offset_columns(outcome_token.span.start))?;
+
+        tokens.finish("end of line")?;
+
+        Ok(Matchup { player, opponent })
+    }
+
+    pub fn get_opponent(&self) -> &Opponent {
+        &self.opponent
+    }
 
-        Matchup { player, opponent }
+    pub fn get_player(&self) -> &Player {
+        &self.player
     }
 
     pub fn get_score(&self) -> i32 {
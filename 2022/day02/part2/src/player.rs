@@ -1,20 +1,25 @@
+use aoc_common::ParseError;
+
 use crate::opponent::Choice as OpponentChoice;
 
 #[derive(Debug)]
-pub(crate) struct Player {
+pub struct Player {
     choice: Choice,
     outcome: Outcome,
 }
 
 impl Player {
-    pub fn new(outcome_string: &str, opponent_choice: &OpponentChoice) -> Player {
-        let outcome = Outcome::from_value(outcome_string);
-        let choice = Self::get_choice(opponent_choice, &outcome);
+    pub fn new(
+        outcome_string: &str,
+        opponent_choice: &OpponentChoice,
+    ) -> Result<Player, ParseError> {
+        let outcome = Outcome::from_value(outcome_string)?;
+        let choice = Self::choose(opponent_choice, &outcome);
 
-        Player { choice, outcome }
+        Ok(Player { choice, outcome })
     }
 
-    fn get_choice(opponent_choice: &OpponentChoice, outcome: &Outcome) -> Choice {
+    fn choose(opponent_choice: &OpponentChoice, outcome: &Outcome) -> Choice {
         match opponent_choice {
             OpponentChoice::ROCK => match outcome {
                 Outcome::WIN => Choice::PAPER,
@@ -34,6 +39,10 @@ impl Player {
         }
     }
 
+    pub fn get_choice(&self) -> &Choice {
+        &self.choice
+    }
+
     pub fn get_score(&self) -> i32 {
         let choice_score = self.choice.get_score();
         let outcome_score = self.outcome.get_score();
@@ -42,7 +51,7 @@ impl Player {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Eq)]
 pub enum Choice {
     ROCK,
     PAPER,
@@ -60,7 +69,6 @@ impl Choice {
 }
 
 #[derive(Debug)]
-
 enum Outcome {
     WIN,
     LOSS,
@@ -68,12 +76,16 @@ enum Outcome {
 }
 
 impl Outcome {
-    fn from_value(value: &str) -> Outcome {
+    fn from_value(value: &str) -> Result<Outcome, ParseError> {
         match value {
-            "X" => Outcome::LOSS,
-            "Y" => Outcome::TIE,
-            "Z" => Outcome::WIN,
-            _ => panic!("Invalid enum value {}", value),
+            "X" => Ok(Outcome::LOSS),
+            "Y" => Ok(Outcome::TIE),
+            "Z" => Ok(Outcome::WIN),
+            _ => Err(ParseError::unexpected(
+                0..value.len(),
+                "one of X, Y or Z",
+                value,
+            )),
         }
     }
 
@@ -0,0 +1,25 @@
+use day02_part2::{Game, Matchup, OpponentChoice, PlayerChoice};
+
+#[test]
+fn matchup_exposes_both_choices() {
+    let matchup = Matchup::new("A Y").unwrap();
+
+    assert_eq!(matchup.get_opponent().get_choice(), &OpponentChoice::ROCK);
+    assert_eq!(matchup.get_player().get_choice(), &PlayerChoice::ROCK);
+    assert_eq!(matchup.get_score(), 4);
+}
+
+#[test]
+fn game_sums_the_rounds() {
+    let game = Game::new(["A Y", "B X", "C Z"]).unwrap();
+
+    assert_eq!(game.get_score().unwrap(), 12);
+}
+
+#[test]
+fn game_when_round_invalid_reports_its_line() {
+    let error = Game::new(["A Y", "B Q"]).unwrap_err();
+
+    assert_eq!(error.line(), 2);
+    assert_eq!(error.span(), 2..3);
+}
@@ -0,0 +1,97 @@
+use aoc_common::{ParseError, ParseErrorKind};
+
+use crate::rucksack::Rucksack;
+
+#[derive(Debug)]
+pub struct ElfGroup {
+    badge: String,
+}
+
+impl ElfGroup {
+    pub fn new(rucksacks: &[Rucksack]) -> Result<ElfGroup, ParseError> {
+        match Self::find_badge(rucksacks) {
+            Some(badge) => Ok(ElfGroup { badge }),
+            None => {
+                let first = rucksacks[0].get_all_items().concat();
+                Err(ParseError::new(
+                    ParseErrorKind::MissingToken,
+                    0..first.len(),
+                    "an item carried by all three elves",
+                    first,
+                ))
+            }
+        }
+    }
+
+    pub fn get_badge_priority(&self) -> i32 {
+        // The badge comes from a rucksack, whose items were validated when it was parsed.
+        Rucksack::get_item_priority(&self.badge).unwrap_or_default()
+    }
+
+    pub fn get_badge(&self) -> &str {
+        &self.badge
+    }
+
+    fn find_badge(rucksacks: &[Rucksack]) -> Option<String> {
+        let items1 = rucksacks[0].get_all_items();
+        let items2 = rucksacks[1].get_all_items();
+        let items3 = rucksacks[2].get_all_items();
+
+        items1
+            .into_iter()
+            .find(|item| items2.contains(item) && items3.contains(item))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn group(inputs: [&str; 3]) -> Result<ElfGroup, ParseError> {
+        let rucksacks: Vec<Rucksack> = inputs
+            .into_iter()
+            .map(|input| Rucksack::new(input).unwrap())
+            .collect();
+        ElfGroup::new(&rucksacks)
+    }
+
+    #[test]
+    fn get_badge_returns_common_item() {
+        let result = group(["abc", "aBC", "aBc"]).unwrap();
+
+        assert_eq!(result.get_badge(), "a");
+    }
+
+    #[test]
+    fn get_badge_example1() {
+        let result = group([
+            "vJrwpWtwJgWrhcsFMMfFFhFp",
+            "jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL",
+            "PmmdzqPrVvPwwTWBwg",
+        ])
+        .unwrap();
+
+        assert_eq!(result.get_badge(), "r");
+    }
+
+    #[test]
+    fn get_badge_example2() {
+        let result = group([
+            "wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn",
+            "ttgJtRGJQctTZtZT",
+            "CrZsJsPPZsGzwwsLwLmpwMDw",
+        ])
+        .unwrap();
+
+        assert_eq!(result.get_badge(), "Z");
+    }
+
+    #[test]
+    fn new_when_no_common_item_returns_error() {
+        let result = group(["abc", "def", "ghi"]).unwrap_err();
+
+        assert_eq!(result.kind(), ParseErrorKind::MissingToken);
+        assert_eq!(result.expected(), "an item carried by all three elves");
+        assert_eq!(result.found(), "abc");
+    }
+}
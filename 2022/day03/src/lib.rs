@@ -0,0 +1,121 @@
+mod elf_group;
+mod rucksack;
+
+use std::fmt::Display;
+
+use aoc_common::{checked_sum, parse_lines, Error, Overflow, ParseError, Solution};
+
+pub use crate::elf_group::ElfGroup;
+pub use crate::rucksack::Rucksack;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = (Vec<Rucksack>, Vec<ElfGroup>);
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let rucksacks = parse_lines(input, Rucksack::new)?;
+        if !rucksacks.len().is_multiple_of(3) {
+            let error = ParseError::missing(0, "a rucksack to complete the group of three");
+            return Err(error.offset_lines(rucksacks.len()).into());
+        }
+
+        let mut elf_groups = Vec::new();
+        for (index, group) in rucksacks.chunks(3).enumerate() {
+            let elf_group = ElfGroup::new(group).map_err(|error| error.offset_lines(index * 3))?;
+            elf_groups.push(elf_group);
+        }
+
+        Ok((rucksacks, elf_groups))
+    }
+
+    fn part1((rucksacks, _): &Self::Input) -> Result<impl Display, Error> {
+        let priorities = rucksacks
+            .iter()
+            .map(|rucksack| i64::from(rucksack.get_duplicates_priority()));
+        Ok(total_duplicate_priority(priorities)?)
+    }
+
+    fn part2((_, elf_groups): &Self::Input) -> Result<impl Display, Error> {
+        let priorities = elf_groups
+            .iter()
+            .map(|elf_group| i64::from(elf_group.get_badge_priority()));
+        Ok(total_badge_priority(priorities)?)
+    }
+}
+
+fn total_duplicate_priority(priorities: impl IntoIterator<Item = i64>) -> Result<i64, Overflow> {
+    checked_sum(priorities, "total duplicate priority")
+}
+
+fn total_badge_priority(priorities: impl IntoIterator<Item = i64>) -> Result<i64, Overflow> {
+    checked_sum(priorities, "total badge priority")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = include_str!("../input_test.txt");
+
+    #[test]
+    fn parse_when_incomplete_group_reports_missing_line() {
+        let result = Day03::parse("abc\nabc\nabc\nabc");
+
+        assert!(matches!(result, Err(Error::Parse(error)) if error.line() == 5));
+    }
+
+    #[test]
+    fn parse_when_invalid_item_reports_rucksack_line() {
+        let result = Day03::parse("abc\nabc\na1c");
+
+        assert!(
+            matches!(result, Err(Error::Parse(error)) if error.line() == 3 && error.span() == (1..2))
+        );
+    }
+
+    #[test]
+    fn parse_when_no_badge_reports_first_line_of_group() {
+        let result = Day03::parse("abc\nabc\nabc\nabc\ndef\nghi");
+
+        assert!(matches!(result, Err(Error::Parse(error)) if error.line() == 4));
+    }
+
+    #[test]
+    fn total_duplicate_priority_adds_up_to_the_maximum() {
+        assert_eq!(total_duplicate_priority([i64::MAX - 52, 52]), Ok(i64::MAX));
+    }
+
+    #[test]
+    fn total_duplicate_priority_when_past_maximum_returns_overflow() {
+        let result = total_duplicate_priority([i64::MAX, 1]).unwrap_err();
+
+        assert_eq!(result.what(), "total duplicate priority");
+    }
+
+    #[test]
+    fn total_badge_priority_adds_up_to_the_maximum() {
+        assert_eq!(total_badge_priority([i64::MAX - 52, 52]), Ok(i64::MAX));
+    }
+
+    #[test]
+    fn total_badge_priority_when_past_maximum_returns_overflow() {
+        let result = total_badge_priority([i64::MAX, 1]).unwrap_err();
+
+        assert_eq!(result.what(), "total badge priority");
+    }
+
+    #[test]
+    fn part1_example() {
+        let input = Day03::parse(INPUT).unwrap();
+
+        assert_eq!(Day03::part1(&input).unwrap().to_string(), "157");
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day03::parse(INPUT).unwrap();
+
+        assert_eq!(Day03::part2(&input).unwrap().to_string(), "70");
+    }
+}
@@ -0,0 +1,29 @@
+use std::process::ExitCode;
+
+use aoc_common::{exit_code, DayArgs, Error, Format, Report, Solution};
+use clap::Parser;
+use day03::Day03;
+
+fn main() -> ExitCode {
+    exit_code(run())
+}
+
+fn run() -> Result<(), Error> {
+    let args = DayArgs::parse();
+    let input = args.input.load()?;
+    if args.format == Format::Json {
+        println!("{}", Report::part1::<Day03>(2022, 3, &input)?.to_json());
+        println!("{}", Report::part2::<Day03>(2022, 3, &input)?.to_json());
+        return Ok(());
+    }
+
+    let rucksacks = Day03::parse(&input)?;
+
+    let duplicates = Day03::part1(&rucksacks)?;
+    println!("Total priority of duplicates was {duplicates}");
+
+    let badges = Day03::part2(&rucksacks)?;
+    println!("Total priority of badges was {badges}");
+
+    Ok(())
+}
@@ -1,60 +1,81 @@
 use std::collections::HashSet;
 
+use aoc_common::ParseError;
 use libm::floorf;
 
-pub(crate) struct Rucksack {
+#[derive(Debug)]
+pub struct Rucksack {
     compartiment1: Vec<String>,
     compartiment2: Vec<String>,
 }
 
 impl Rucksack {
-    pub fn new(item_string: &str) -> Rucksack {
+    pub fn new(item_string: &str) -> Result<Rucksack, ParseError> {
+        for (column, item) in item_string.char_indices() {
+            Rucksack::get_item_priority(&item.to_string())
+                .map_err(|error| error.offset_columns(column))?;
+        }
+
         let items: Vec<String> = item_string
             .split("")
-            .map(|item| String::from(item))
+            .map(String::from)
             .filter(|item| !String::is_empty(item))
             .collect();
 
         let midpoint = floorf((items.len() / 2) as f32) as usize; //Workaround since built-in floor operator is still unstable
         let compartiments = items.split_at(midpoint);
 
-        Rucksack {
+        Ok(Rucksack {
             compartiment1: compartiments.0.to_vec(),
             compartiment2: compartiments.1.to_vec(),
-        }
+        })
     }
 
     pub fn get_duplicates_priority(&self) -> i32 {
         let duplicates = self.find_duplicates();
         let mut score = 0;
         for duplicate in duplicates {
-            score += Rucksack::get_item_priority(duplicate);
+            // Every item was validated when the rucksack was parsed.
+            score += Rucksack::get_item_priority(duplicate).unwrap_or_default();
         }
 
         score
     }
 
+    pub fn get_all_items(&self) -> Vec<String> {
+        let compartiment1 = self.compartiment1.to_owned();
+        let compartiment2 = self.compartiment2.to_owned();
+
+        [compartiment1, compartiment2].concat()
+    }
+
     fn find_duplicates(&self) -> HashSet<&str> {
         let mut duplicates: HashSet<&str> = HashSet::new();
         for item in &self.compartiment1 {
-            if self.compartiment2.contains(&item) {
-                duplicates.insert(&item);
+            if self.compartiment2.contains(item) {
+                duplicates.insert(item);
             }
         }
         duplicates
     }
 
-    fn get_item_priority(item: &str) -> i32 {
+    pub fn get_item_priority(item: &str) -> Result<i32, ParseError> {
         let score_legend = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
-        let index = score_legend.find(item).expect("Invalid item.");
-
-        (index + 1) as i32
+        match score_legend.find(item) {
+            Some(index) => Ok((index + 1) as i32),
+            None => Err(ParseError::unexpected(
+                0..item.len(),
+                "an item between a-z or A-Z",
+                item,
+            )),
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_common::ParseErrorKind;
 
     #[test]
     fn new_splits_string_in_two_parts() {
@@ -62,7 +83,7 @@ mod tests {
         let expected1 = vec!["a".to_owned(), "b".to_owned()];
         let expected2 = vec!["c".to_owned(), "d".to_owned()];
 
-        let result = Rucksack::new(&input);
+        let result = Rucksack::new(&input).unwrap();
 
         assert_eq!(result.compartiment1, expected1);
         assert_eq!(result.compartiment2, expected2);
@@ -71,7 +92,7 @@ mod tests {
     #[test]
     fn find_duplicates_when_none_returns_empty() {
         let input = "abcd".to_owned();
-        let rucksack = Rucksack::new(&input);
+        let rucksack = Rucksack::new(&input).unwrap();
 
         assert!(rucksack.find_duplicates().is_empty());
     }
@@ -90,7 +111,7 @@ mod tests {
     }
 
     fn test_find_duplicates(input: &str, expected_duplicate_element: &str) {
-        let rucksack = Rucksack::new(&input);
+        let rucksack = Rucksack::new(input).unwrap();
 
         let mut expected = HashSet::new();
         expected.insert(expected_duplicate_element);
@@ -110,26 +131,52 @@ mod tests {
 
     #[test]
     fn get_item_priority_when_lowercase_returns_score_1_to_26() {
-        assert_eq!(Rucksack::get_item_priority("a"), 1);
-        assert_eq!(Rucksack::get_item_priority("p"), 16);
-        assert_eq!(Rucksack::get_item_priority("v"), 22);
+        assert_eq!(Rucksack::get_item_priority("a"), Ok(1));
+        assert_eq!(Rucksack::get_item_priority("p"), Ok(16));
+        assert_eq!(Rucksack::get_item_priority("v"), Ok(22));
     }
 
     #[test]
     fn get_item_priority_when_uppercase_returns_score_27_to_52() {
-        assert_eq!(Rucksack::get_item_priority("A"), 27);
-        assert_eq!(Rucksack::get_item_priority("P"), 42);
-        assert_eq!(Rucksack::get_item_priority("L"), 38);
+        assert_eq!(Rucksack::get_item_priority("A"), Ok(27));
+        assert_eq!(Rucksack::get_item_priority("P"), Ok(42));
+        assert_eq!(Rucksack::get_item_priority("L"), Ok(38));
+    }
+
+    #[test]
+    fn get_item_priority_when_not_a_letter_returns_error() {
+        let result = Rucksack::get_item_priority("1").unwrap_err();
+
+        assert_eq!(result.kind(), ParseErrorKind::UnexpectedToken);
+        assert_eq!(result.found(), "1");
+    }
+
+    #[test]
+    fn new_when_invalid_item_reports_column() {
+        let result = Rucksack::new("abc-ef").unwrap_err();
+
+        assert_eq!(result.span(), 3..4);
+        assert_eq!(result.found(), "-");
     }
 
     #[test]
     fn get_item_priority_examples() {
-        assert_eq!(Rucksack::get_item_priority("p"), 16);
-        assert_eq!(Rucksack::get_item_priority("L"), 38);
-        assert_eq!(Rucksack::get_item_priority("P"), 42);
-        assert_eq!(Rucksack::get_item_priority("v"), 22);
-        assert_eq!(Rucksack::get_item_priority("t"), 20);
-        assert_eq!(Rucksack::get_item_priority("s"), 19);
+        assert_eq!(Rucksack::get_item_priority("p"), Ok(16));
+        assert_eq!(Rucksack::get_item_priority("L"), Ok(38));
+        assert_eq!(Rucksack::get_item_priority("P"), Ok(42));
+        assert_eq!(Rucksack::get_item_priority("v"), Ok(22));
+        assert_eq!(Rucksack::get_item_priority("t"), Ok(20));
+        assert_eq!(Rucksack::get_item_priority("s"), Ok(19));
+    }
+
+    #[test]
+    fn get_all_items_returns_original_input_as_vector() {
+        let input = "abcDEF";
+        let expected = vec!["a", "b", "c", "D", "E", "F"];
+
+        let rucksack = Rucksack::new(input).unwrap();
+
+        assert_eq!(rucksack.get_all_items(), expected);
     }
 
     #[test]
@@ -153,7 +200,7 @@ mod tests {
     }
 
     fn test_get_duplicates_priority(input: &str, expected: i32) {
-        let rucksack = Rucksack::new(&input);
+        let rucksack = Rucksack::new(input).unwrap();
 
         assert_eq!(rucksack.get_duplicates_priority(), expected);
     }
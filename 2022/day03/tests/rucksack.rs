@@ -0,0 +1,26 @@
+use day03::{ElfGroup, Rucksack};
+
+#[test]
+fn rucksack_scores_the_item_in_both_compartments() {
+    let rucksack = Rucksack::new("vJrwpWtwJgWrhcsFMMfFFhFp").unwrap();
+
+    assert_eq!(rucksack.get_duplicates_priority(), 16);
+    assert_eq!(Rucksack::get_item_priority("p").unwrap(), 16);
+}
+
+#[test]
+fn elf_group_finds_the_badge() {
+    let rucksacks: Vec<Rucksack> = [
+        "vJrwpWtwJgWrhcsFMMfFFhFp",
+        "jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL",
+        "PmmdzqPrVvPwwTWBwg",
+    ]
+    .into_iter()
+    .map(|line| Rucksack::new(line).unwrap())
+    .collect();
+
+    let group = ElfGroup::new(&rucksacks).unwrap();
+
+    assert_eq!(group.get_badge(), "r");
+    assert_eq!(group.get_badge_priority(), 18);
+}
@@ -1,32 +1,41 @@
-pub(crate) struct Group {
+use aoc_common::{ParseError, Token, Tokens};
+
+#[derive(Debug)]
+pub struct Group {
     section1: Vec<i32>,
     section2: Vec<i32>,
 }
 
 impl Group {
-    pub fn new(input_string: &str) -> Group {
-        let split_input: Vec<&str> = input_string.split(",").collect();
-        if split_input.len() != 2 {
-            panic!(
-                "Unexpected number of elves in group: {}, expected 2 groups, but found {}",
-                input_string,
-                split_input.len()
-            );
-        }
+    pub fn new(input_string: &str) -> Result<Group, ParseError> {
+        let mut tokens = Tokens::new(input_string, ',');
 
         let mut sections: Vec<Vec<i32>> = Vec::new();
-        for unparsed in split_input {
-            let boundaries: Vec<i32> = unparsed
-                .split("-")
-                .map(|x| x.parse().expect("Not a valid number {x}"))
-                .collect();
-            sections.push((boundaries[0]..=boundaries[1]).into_iter().collect());
+        for _ in 0..2 {
+            let unparsed = tokens.expect("a section range for each of the 2 elves")?;
+            let section = Self::parse_section(&unparsed)
+                .map_err(|error| error.offset_columns(unparsed.span.start))?;
+            sections.push(section);
         }
+        tokens.finish("exactly 2 elves in the group")?;
 
-        Group {
+        Ok(Group {
             section1: sections[0].to_owned(),
             section2: sections[1].to_owned(),
-        }
+        })
+    }
+
+    fn parse_section(unparsed: &Token) -> Result<Vec<i32>, ParseError> {
+        let mut boundaries = Tokens::new(unparsed.text, '-');
+        let start: i32 = boundaries
+            .expect("a section start")?
+            .parse_number("a section start")?;
+        let end: i32 = boundaries
+            .expect("a section end")?
+            .parse_number("a section end")?;
+        boundaries.finish("end of section range")?;
+
+        Ok((start..=end).collect())
     }
 
     pub fn is_pair_fully_contained_in_other(&self) -> bool {
@@ -61,6 +70,7 @@ impl Group {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_common::ParseErrorKind;
 
     #[test]
     fn new_parses_input() {
@@ -69,12 +79,36 @@ mod tests {
         let expected1 = vec![2, 3, 4];
         let expected2 = vec![6, 7, 8];
 
-        let result = Group::new(&input);
+        let result = Group::new(input).unwrap();
 
         assert_eq!(result.section1, expected1);
         assert_eq!(result.section2, expected2);
     }
 
+    #[test]
+    fn new_when_three_elves_returns_error() {
+        let result = Group::new("2-4,6-8,1-2").unwrap_err();
+
+        assert_eq!(result.kind(), ParseErrorKind::UnexpectedToken);
+        assert_eq!(result.span(), 8..11);
+    }
+
+    #[test]
+    fn new_when_one_elf_returns_missing() {
+        let result = Group::new("2-4").unwrap_err();
+
+        assert_eq!(result.kind(), ParseErrorKind::MissingToken);
+    }
+
+    #[test]
+    fn new_when_invalid_number_reports_span() {
+        let result = Group::new("2-4,6-x").unwrap_err();
+
+        assert_eq!(result.kind(), ParseErrorKind::InvalidNumber);
+        assert_eq!(result.span(), 6..7);
+        assert_eq!(result.expected(), "a section end");
+    }
+
     #[test]
     fn is_pair_fully_contained_in_other_when_no_overlap_false() {
         test_is_pair_fully_contained_in_other("2-4,6-8", false);
@@ -96,7 +130,7 @@ mod tests {
     }
 
     fn test_is_pair_fully_contained_in_other(input: &str, expected: bool) {
-        let group = Group::new(input);
+        let group = Group::new(input).unwrap();
         assert_eq!(group.is_pair_fully_contained_in_other(), expected);
     }
 
@@ -111,7 +145,7 @@ mod tests {
     }
 
     fn test_has_overlap(input: &str, expected: bool) {
-        let group = Group::new(input);
+        let group = Group::new(input).unwrap();
         assert_eq!(group.has_overlap(), expected);
     }
 }
@@ -0,0 +1,50 @@
+mod group;
+
+use std::fmt::Display;
+
+use aoc_common::{parse_lines, Error, Solution};
+
+pub use crate::group::Group;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Vec<Group>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let groups = parse_lines(input, Group::new)?;
+        Ok(groups)
+    }
+
+    fn part1(groups: &Self::Input) -> Result<impl Display, Error> {
+        Ok(groups
+            .iter()
+            .filter(|group| group.is_pair_fully_contained_in_other())
+            .count())
+    }
+
+    fn part2(groups: &Self::Input) -> Result<impl Display, Error> {
+        Ok(groups.iter().filter(|group| group.has_overlap()).count())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = include_str!("../input_test.txt");
+
+    #[test]
+    fn part1_example() {
+        let groups = Day04::parse(INPUT).unwrap();
+
+        assert_eq!(Day04::part1(&groups).unwrap().to_string(), "2");
+    }
+
+    #[test]
+    fn part2_example() {
+        let groups = Day04::parse(INPUT).unwrap();
+
+        assert_eq!(Day04::part2(&groups).unwrap().to_string(), "4");
+    }
+}
@@ -0,0 +1,29 @@
+use std::process::ExitCode;
+
+use aoc_common::{exit_code, DayArgs, Error, Format, Report, Solution};
+use clap::Parser;
+use day04::Day04;
+
+fn main() -> ExitCode {
+    exit_code(run())
+}
+
+fn run() -> Result<(), Error> {
+    let args = DayArgs::parse();
+    let input = args.input.load()?;
+    if args.format == Format::Json {
+        println!("{}", Report::part1::<Day04>(2022, 4, &input)?.to_json());
+        println!("{}", Report::part2::<Day04>(2022, 4, &input)?.to_json());
+        return Ok(());
+    }
+
+    let groups = Day04::parse(&input)?;
+
+    let fully_contained = Day04::part1(&groups)?;
+    let overlaps = Day04::part2(&groups)?;
+
+    println!("part 1 = {fully_contained}");
+    println!("part 2 = {overlaps}");
+
+    Ok(())
+}
@@ -0,0 +1,13 @@
+use day04::Group;
+
+#[test]
+fn group_detects_containment_and_overlap() {
+    let contained = Group::new("2-8,3-7").unwrap();
+    let overlapping = Group::new("5-7,7-9").unwrap();
+    let apart = Group::new("2-4,6-8").unwrap();
+
+    assert!(contained.is_pair_fully_contained_in_other());
+    assert!(!overlapping.is_pair_fully_contained_in_other());
+    assert!(overlapping.has_overlap());
+    assert!(!apart.has_overlap());
+}
@@ -1,4 +1,5 @@
-pub(crate) struct CrateStack {
+#[derive(Debug, Clone, Default)]
+pub struct CrateStack {
     crates: Vec<String>,
 }
 
@@ -7,6 +8,7 @@ impl CrateStack {
         CrateStack { crates: Vec::new() }
     }
 
+    #[cfg(test)]
     pub fn new_with_initial(initial_crate: &str) -> CrateStack {
         CrateStack {
             crates: vec![String::from(initial_crate)],
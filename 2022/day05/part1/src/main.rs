@@ -1,34 +1,25 @@
-use util::read_all_lines;
+use std::process::ExitCode;
 
-use crate::{instruction::Instruction, stacks::Stacks};
+use aoc_common::{exit_code, DayArgs, Error, Format, Report, Solution};
+use clap::Parser;
+use day05_part1::Day05;
 
-mod crate_stack;
-mod instruction;
-mod stacks;
-mod util;
-
-fn main() {
-    let lines = read_all_lines("./input.txt");
-
-    let mut initial_reversed: Vec<&str> = Vec::new();
-    let mut instructions: Vec<Instruction> = Vec::new();
+fn main() -> ExitCode {
+    exit_code(run())
+}
 
-    let mut instructions_reached: bool = false;
-    for line in &lines {
-        if line.starts_with(" 1") {
-            instructions_reached = true;
-            continue;
-        }
-        if !instructions_reached {
-            initial_reversed.push(&line);
-        } else if !line.is_empty() {
-            instructions.push(Instruction::new(&line));
-        }
+fn run() -> Result<(), Error> {
+    let args = DayArgs::parse();
+    let input = args.input.load()?;
+    if args.format == Format::Json {
+        println!("{}", Report::part1::<Day05>(2022, 5, &input)?.to_json());
+        return Ok(());
     }
 
-    let mut stacks = Stacks::new(initial_reversed);
-    stacks.process_instructions(instructions);
+    let procedure = Day05::parse(&input)?;
+
+    let tops = Day05::part1(&procedure)?;
+    println!("top crates: {tops}");
 
-    let tops = stacks.get_top_crates();
-    println!("top crates: {tops:?}");
+    Ok(())
 }
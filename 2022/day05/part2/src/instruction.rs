@@ -1,39 +1,47 @@
-use regex::Regex;
+use aoc_common::{ParseError, Token};
+use regex::{Captures, Regex};
 
 #[derive(Debug)]
-pub(crate) struct Instruction {
+pub struct Instruction {
     from: usize,
     to: usize,
     amount: i32,
 }
 
 impl Instruction {
-    pub fn new(input: &str) -> Instruction {
+    pub fn new(input: &str) -> Result<Instruction, ParseError> {
         let regex =
             Regex::new(r"move (?P<amount>\d+?) from (?P<from>\d+?) to (?P<to>\d+?)").unwrap();
-        let captured = regex.captures(input).unwrap();
-        let amount: i32 = captured
-            .name("amount")
-            .expect("no 'amount' found in instruction")
-            .as_str()
-            .parse::<i32>()
-            .expect("error parsing 'amount'");
-
-        let from: usize = captured
-            .name("from")
-            .expect("no from found in instruction")
-            .as_str()
-            .parse::<usize>()
-            .expect("error parsing 'from'");
-
-        let to: usize = captured
-            .name("to")
-            .expect("no 'to' found in instruction")
-            .as_str()
-            .parse::<usize>()
-            .expect("error parsing 'to'");
-
-        Instruction { from, to, amount }
+        let captured = regex.captures(input).ok_or_else(|| {
+            ParseError::unexpected(
+                0..input.len(),
+                "an instruction like 'move 1 from 2 to 3'",
+                input,
+            )
+        })?;
+
+        let amount: i32 = Self::parse_capture(&captured, "amount", input)?;
+        let from: usize = Self::parse_capture(&captured, "from", input)?;
+        let to: usize = Self::parse_capture(&captured, "to", input)?;
+
+        Ok(Instruction { from, to, amount })
+    }
+
+    fn parse_capture<T: std::str::FromStr>(
+        captured: &Captures,
+        name: &str,
+        input: &str,
+    ) -> Result<T, ParseError> {
+        let expected = format!("a number for '{name}'");
+        let matched = captured
+            .name(name)
+            .ok_or_else(|| ParseError::missing(input.len(), expected.as_str()))?;
+
+        let token = Token {
+            text: matched.as_str(),
+            span: matched.range(),
+        };
+        token.parse_number(&expected)
     }
 
     pub fn get_amount(&self) -> &i32 {
@@ -52,14 +60,31 @@ impl Instruction {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_common::ParseErrorKind;
 
     #[test]
     fn new_parses_instruction() {
         let input = "move 1 from 2 to 3";
-        let result = Instruction::new(input);
+        let result = Instruction::new(input).unwrap();
 
         assert_eq!(result.amount, 1);
         assert_eq!(result.from, 2);
         assert_eq!(result.to, 3);
     }
+
+    #[test]
+    fn new_when_not_an_instruction_returns_error() {
+        let result = Instruction::new("push 1 onto 2").unwrap_err();
+
+        assert_eq!(result.kind(), ParseErrorKind::UnexpectedToken);
+        assert_eq!(result.span(), 0..13);
+    }
+
+    #[test]
+    fn new_when_amount_too_large_reports_span() {
+        let result = Instruction::new("move 99999999999 from 1 to 2").unwrap_err();
+
+        assert_eq!(result.kind(), ParseErrorKind::InvalidNumber);
+        assert_eq!(result.span(), 5..16);
+    }
 }
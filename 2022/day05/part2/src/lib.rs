@@ -0,0 +1,66 @@
+mod crate_stack;
+mod instruction;
+mod stacks;
+
+use std::fmt::Display;
+
+use aoc_common::{Error, ParseError, Solution};
+
+pub use crate::crate_stack::CrateStack;
+pub use crate::instruction::Instruction;
+pub use crate::stacks::Stacks;
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = (Stacks, Vec<Instruction>);
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let mut initial_reversed: Vec<&str> = Vec::new();
+        let mut instructions: Vec<Instruction> = Vec::new();
+
+        let mut instructions_reached: bool = false;
+        for (index, line) in input.lines().enumerate() {
+            if line.starts_with(" 1") {
+                instructions_reached = true;
+                continue;
+            }
+            if !instructions_reached {
+                initial_reversed.push(line);
+            } else if !line.is_empty() {
+                let instruction =
+                    Instruction::new(line).map_err(|error| error.offset_lines(index))?;
+                instructions.push(instruction);
+            }
+        }
+
+        if !instructions_reached {
+            let error = ParseError::missing(0, "stack numbers below the crate diagram");
+            return Err(error.offset_lines(initial_reversed.len()).into());
+        }
+
+        let stacks = Stacks::new(initial_reversed)?;
+        Ok((stacks, instructions))
+    }
+
+    fn part2((stacks, instructions): &Self::Input) -> Result<impl Display, Error> {
+        let mut stacks = stacks.clone();
+        stacks.process_instructions(instructions);
+
+        Ok(stacks.get_top_crates().concat())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = include_str!("../input_test.txt");
+
+    #[test]
+    fn part2_example() {
+        let procedure = Day05::parse(INPUT).unwrap();
+
+        assert_eq!(Day05::part2(&procedure).unwrap().to_string(), "MCD");
+    }
+}
@@ -1,22 +1,35 @@
+use aoc_common::ParseError;
+
 use crate::{crate_stack::CrateStack, instruction::Instruction};
 
-pub(crate) struct Stacks {
+#[derive(Debug, Clone)]
+pub struct Stacks {
     crate_stacks: Vec<CrateStack>,
 }
 
 impl Stacks {
-    pub fn new(input_strings: Vec<&str>) -> Stacks {
+    pub fn new(input_strings: Vec<&str>) -> Result<Stacks, ParseError> {
+        if input_strings.is_empty() {
+            return Err(ParseError::missing(0, "a crate diagram"));
+        }
+
         let mut crate_stacks: Vec<CrateStack> = Vec::new();
-        let nb_stacks = libm::ceil((input_strings[0].len() as f64) / (4 as f64)) as usize;
+        let nb_stacks = libm::ceil((input_strings[0].len() as f64) / 4_f64) as usize;
 
         for _ in 0..nb_stacks {
             crate_stacks.push(CrateStack::new());
         }
 
         let tot = input_strings[0].len() as i32 + 1;
-        for line in input_strings.iter().rev() {
+        for (line_index, line) in input_strings.iter().enumerate().rev() {
             for i in (1..tot).step_by(4) {
-                let char = String::from(line.chars().nth(i as usize).unwrap());
+                let char = match line.chars().nth(i as usize) {
+                    Some(char) => String::from(char),
+                    None => {
+                        let error = ParseError::missing(line.len(), "a crate or a blank");
+                        return Err(error.offset_lines(line_index));
+                    }
+                };
                 if char != " " {
                     let index = ((i - 1) / 4) as usize;
                     crate_stacks[index].add_crate(&char);
@@ -24,12 +37,12 @@ impl Stacks {
             }
         }
 
-        Stacks { crate_stacks }
+        Ok(Stacks { crate_stacks })
     }
 
-    pub fn process_instructions(&mut self, instructions: Vec<Instruction>) {
+    pub fn process_instructions(&mut self, instructions: &[Instruction]) {
         for instruction in instructions {
-            self.process_instruction(&instruction);
+            self.process_instruction(instruction);
         }
     }
 
@@ -43,6 +56,10 @@ impl Stacks {
         self.crate_stacks[to - 1].add_n_crates(to_move);
     }
 
+    pub fn get_crate_stacks(&self) -> &[CrateStack] {
+        &self.crate_stacks
+    }
+
     pub fn get_top_crates(&self) -> Vec<String> {
         let mut tops = Vec::new();
         for stack in &self.crate_stacks {
@@ -56,15 +73,16 @@ impl Stacks {
 #[cfg(test)]
 mod test {
     use super::*;
+    use aoc_common::ParseErrorKind;
 
     #[test]
     fn new_parses_input() {
         let top_row = "    [D]    ";
         let bottom_row = "[N] [C]    ";
 
-        let input = vec![bottom_row, top_row];
+        let input = vec![top_row, bottom_row];
 
-        let stacks = Stacks::new(input);
+        let stacks = Stacks::new(input).unwrap();
 
         assert_eq!(stacks.crate_stacks.len(), 3);
         assert_eq!(stacks.crate_stacks[0].get_crates(), &vec!["N"]);
@@ -72,15 +90,26 @@ mod test {
         assert!(stacks.crate_stacks[2].get_crates().is_empty());
     }
 
+    #[test]
+    fn new_when_row_too_short_reports_row() {
+        let input = vec!["    [D]    ", "[N] [C]"];
+
+        let result = Stacks::new(input).unwrap_err();
+
+        assert_eq!(result.kind(), ParseErrorKind::MissingToken);
+        assert_eq!(result.line(), 2);
+        assert_eq!(result.span(), 7..7);
+    }
+
     #[test]
     fn process_instruction_moves_crate() {
-        let instruction = Instruction::new("move 1 from 2 to 3");
+        let instruction = Instruction::new("move 1 from 2 to 3").unwrap();
         let top_row = "    [D]    ";
         let bottom_row = "[N] [C]    ";
 
-        let input = vec![bottom_row, top_row];
+        let input = vec![top_row, bottom_row];
 
-        let mut stacks = Stacks::new(input);
+        let mut stacks = Stacks::new(input).unwrap();
         stacks.process_instruction(&instruction);
 
         assert_eq!(stacks.crate_stacks.len(), 3);
@@ -91,18 +120,18 @@ mod test {
 
     #[test]
     fn process_instruction_moves_multiple_crates() {
-        let instruction = Instruction::new("move 2 from 2 to 3");
+        let instruction = Instruction::new("move 2 from 2 to 3").unwrap();
         let top_row = "    [D]    ";
         let bottom_row = "[N] [C]    ";
 
-        let input = vec![bottom_row, top_row];
+        let input = vec![top_row, bottom_row];
 
-        let mut stacks = Stacks::new(input);
+        let mut stacks = Stacks::new(input).unwrap();
         stacks.process_instruction(&instruction);
 
         assert_eq!(stacks.crate_stacks.len(), 3);
         assert_eq!(stacks.crate_stacks[0].get_crates(), &vec!["N"]);
         assert!(stacks.crate_stacks[1].get_crates().is_empty());
-        assert_eq!(stacks.crate_stacks[2].get_crates(), &vec!["D", "C"]);
+        assert_eq!(stacks.crate_stacks[2].get_crates(), &vec!["C", "D"]);
     }
 }
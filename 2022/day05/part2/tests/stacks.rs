@@ -0,0 +1,25 @@
+use day05_part2::{Instruction, Stacks};
+
+fn stacks() -> Stacks {
+    Stacks::new(vec!["    [D]    ", "[N] [C]    ", "[Z] [M] [P]"]).unwrap()
+}
+
+#[test]
+fn stacks_exposes_crates_bottom_first() {
+    let stacks = stacks();
+
+    let crates = stacks.get_crate_stacks();
+    assert_eq!(crates.len(), 3);
+    assert_eq!(crates[1].get_crates(), &["M", "C", "D"]);
+    assert_eq!(stacks.get_top_crates(), ["N", "D", "P"]);
+}
+
+#[test]
+fn process_instructions_moves_crates() {
+    let mut stacks = stacks();
+    let instruction = Instruction::new("move 2 from 1 to 3").unwrap();
+
+    stacks.process_instructions(&[instruction]);
+
+    assert_eq!(stacks.get_top_crates(), ["", "D", "N"]);
+}
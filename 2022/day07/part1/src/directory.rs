@@ -1,3 +1,5 @@
+use aoc_common::{checked_sum, Overflow, ParseError, Tokens};
+
 use crate::file::File;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
@@ -16,14 +18,15 @@ impl Directory {
         }
     }
 
-    pub fn new_from_string(input_string: &str, parent_index: usize) -> Self {
-        let split_line: Vec<&str> = input_string.split(" ").collect();
-        let name = split_line.get(1).expect("Could not find name in input");
-        Directory {
-            name: String::from(*name),
+    pub fn new_from_string(input_string: &str, parent_index: usize) -> Result<Self, ParseError> {
+        let mut tokens = Tokens::new(input_string, ' ');
+        tokens.expect("'dir'")?;
+        let name = tokens.expect("a directory name")?;
+        Ok(Directory {
+            name: String::from(name.text),
             files: Vec::new(),
             parent_index: Some(parent_index),
-        }
+        })
     }
 
     pub fn add_file(&mut self, file: File) {
@@ -38,12 +41,9 @@ impl Directory {
         self.parent_index
     }
 
-    pub fn size(&self) -> i32 {
-        let mut result = 0;
-        for file in &self.files {
-            result += file.size();
-        }
-        result
+    /// Total size of the files directly in this directory.
+    pub fn size(&self) -> Result<u64, Overflow> {
+        checked_sum(self.files.iter().map(File::size), "directory size")
     }
 
     pub fn is_root(&self) -> bool {
@@ -61,4 +61,37 @@ mod tests {
 
         assert_eq!(result.name, "/");
     }
+
+    #[test]
+    fn new_from_string_parses_name() {
+        let result = Directory::new_from_string("dir a", 0).unwrap();
+
+        assert_eq!(result.name, "a");
+        assert_eq!(result.parent_index, Some(0));
+    }
+
+    #[test]
+    fn new_from_string_when_name_missing_returns_error() {
+        let result = Directory::new_from_string("dir", 0).unwrap_err();
+
+        assert_eq!(result.span(), 3..3);
+    }
+
+    #[test]
+    fn size_when_at_maximum_returns_total() {
+        let mut directory = Directory::new_root();
+        directory.add_file(File::new(&format!("{} a", u64::MAX - 1)).unwrap());
+        directory.add_file(File::new("1 b").unwrap());
+
+        assert_eq!(directory.size(), Ok(u64::MAX));
+    }
+
+    #[test]
+    fn size_when_past_maximum_returns_overflow() {
+        let mut directory = Directory::new_root();
+        directory.add_file(File::new(&format!("{} a", u64::MAX)).unwrap());
+        directory.add_file(File::new("1 b").unwrap());
+
+        assert_eq!(directory.size().unwrap_err().what(), "directory size");
+    }
 }
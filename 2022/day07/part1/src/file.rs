@@ -1,25 +1,24 @@
+use aoc_common::{ParseError, Tokens};
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct File {
-    size: i32,
+    size: u64,
     name: String,
 }
 
 impl File {
-    pub fn new(input_line: &str) -> Self {
-        let split_line: Vec<&str> = input_line.split(" ").collect();
-        let size_unparsed = split_line.get(0).unwrap();
-        let size = size_unparsed
-            .parse::<i32>()
-            .expect("input was not a valid size");
-
-        let name = split_line.get(1).expect("Could not find name in input");
-        File {
+    pub fn new(input_line: &str) -> Result<Self, ParseError> {
+        let mut tokens = Tokens::new(input_line, ' ');
+        let size = tokens.expect("a file size")?.parse_number("a file size")?;
+        let name = tokens.expect("a file name")?;
+
+        Ok(File {
             size,
-            name: String::from(*name),
-        }
+            name: String::from(name.text),
+        })
     }
 
-    pub fn size(&self) -> i32 {
+    pub fn size(&self) -> u64 {
         self.size
     }
 }
@@ -27,16 +26,32 @@ impl File {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_common::ParseErrorKind;
 
     #[test]
     fn new_parses_size() {
-        let result = File::new("14848514 b.txt");
+        let result = File::new("14848514 b.txt").unwrap();
         assert_eq!(result.size, 14848514);
     }
 
     #[test]
     fn new_parses_name() {
-        let result = File::new("14848514 b.txt");
+        let result = File::new("14848514 b.txt").unwrap();
         assert_eq!(result.name, "b.txt");
     }
+
+    #[test]
+    fn new_when_size_invalid_returns_error() {
+        let result = File::new("big b.txt").unwrap_err();
+
+        assert_eq!(result.kind(), ParseErrorKind::InvalidNumber);
+        assert_eq!(result.span(), 0..3);
+    }
+
+    #[test]
+    fn new_when_name_missing_returns_error() {
+        let result = File::new("14848514").unwrap_err();
+
+        assert_eq!(result.kind(), ParseErrorKind::MissingToken);
+    }
 }
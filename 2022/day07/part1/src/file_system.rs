@@ -1,46 +1,66 @@
 use std::collections::HashMap;
 
+use aoc_common::{checked_sum, Overflow, ParseError};
+
 use crate::{
     directory::Directory,
     file::File,
     instruction::{Instruction, Type},
 };
 
+#[derive(Default)]
 pub struct FileSystem {
     directories: Vec<Directory>,
+    /// Directory the log is in, `None` until the log's first line is replayed.
+    current_dir_index: Option<usize>,
 }
 
 impl FileSystem {
     pub fn new() -> Self {
         FileSystem {
             directories: Vec::new(),
+            current_dir_index: None,
+        }
+    }
+
+    /// Replays the terminal log line by line. The first line is the `cd /` that starts every log.
+    pub fn process_scan<S: AsRef<str>>(
+        &mut self,
+        logs: impl IntoIterator<Item = S>,
+    ) -> Result<(), ParseError> {
+        for (i, line) in logs.into_iter().enumerate() {
+            self.process_line(line.as_ref())
+                .map_err(|error| error.offset_lines(i))?;
         }
+
+        Ok(())
     }
 
-    pub fn process_scan(&mut self, logs: Vec<String>) {
-        let nb_lines = logs.len();
-
-        let mut current_dir_index = self.add_directory(Directory::new_root());
-
-        let mut i = 1;
-        while i < nb_lines {
-            let line = logs.get(i).unwrap();
-            if self.is_instruction(line) {
-                let instruction = Instruction::new(line);
-                match instruction.command_type() {
-                    Type::NAVIGATE => {
-                        current_dir_index = self.handle_navigate(instruction, current_dir_index)
-                    }
-                    Type::LIST => {}
+    /// Replays one line of the terminal log, so the log can be read as it comes.
+    pub fn process_line(&mut self, line: &str) -> Result<(), ParseError> {
+        let Some(current_dir_index) = self.current_dir_index else {
+            self.current_dir_index = Some(self.add_directory(Directory::new_root()));
+            return Ok(());
+        };
+
+        if self.is_instruction(line) {
+            let instruction = Instruction::new(line)?;
+            match instruction.command_type() {
+                Type::NAVIGATE => {
+                    self.current_dir_index =
+                        Some(self.handle_navigate(instruction, current_dir_index))
                 }
-            } else if self.is_directory_line(line) {
-                let dir = Directory::new_from_string(line, current_dir_index);
-                self.add_directory(dir);
-            } else {
-                self.add_file(current_dir_index, File::new(line));
+                Type::LIST => {}
             }
-            i += 1;
+        } else if self.is_directory_line(line) {
+            let dir = Directory::new_from_string(line, current_dir_index)?;
+            self.add_directory(dir);
+        } else {
+            let file = File::new(line)?;
+            self.add_file(current_dir_index, file);
         }
+
+        Ok(())
     }
 
     fn is_instruction(&self, line: &str) -> bool {
@@ -66,11 +86,12 @@ impl FileSystem {
             .iter()
             .position(|dir| dir.name() == directory.name());
 
-        if index.is_none() {
-            self.directories.push(directory);
-            self.directories.len() - 1
-        } else {
-            index.unwrap()
+        match index {
+            Some(index) => index,
+            None => {
+                self.directories.push(directory);
+                self.directories.len() - 1
+            }
         }
     }
 
@@ -89,32 +110,33 @@ impl FileSystem {
     pub fn get_parent_for_index(&self, current_index: usize) -> usize {
         let current_dir = self.directories.get(current_index).unwrap();
         if current_dir.is_root() {
-            println!("Already at root, returning same index");
             current_index
         } else {
             current_dir.parent_index().unwrap()
         }
     }
 
-    pub fn get_total_dir_size(&self, index: usize) -> i32 {
+    pub fn get_total_dir_size(&self, index: usize) -> Result<u64, Overflow> {
         let current = self.directories.get(index).unwrap();
-        let children: i32 = self
+        let mut sizes = vec![current.size()?];
+        for child in self
             .directories
             .iter()
             .filter(|dir| dir.parent_index() == Some(index))
-            .map(|child| child.size())
-            .sum();
-        children + current.size()
+        {
+            sizes.push(child.size()?);
+        }
+        checked_sum(sizes, "total directory size")
     }
 
-    pub fn get_all_total_sizes(&self) -> HashMap<&str, i32> {
-        let mut result: HashMap<&str, i32> = HashMap::new();
+    pub fn get_all_total_sizes(&self) -> Result<HashMap<&str, u64>, Overflow> {
+        let mut result: HashMap<&str, u64> = HashMap::new();
         for i in 0..self.directories.len() {
             let dir = self.directories.get(i).unwrap();
-            let size = self.get_total_dir_size(i);
+            let size = self.get_total_dir_size(i)?;
             result.insert(dir.name(), size);
         }
-        result
+        Ok(result)
     }
 }
 
@@ -131,4 +153,18 @@ mod tests {
 
         assert_eq!(result, 0);
     }
+
+    #[test]
+    fn get_total_dir_size_when_children_overflow_returns_error() {
+        let mut fs = FileSystem::new();
+        let root = fs.add_directory(Directory::new_root());
+        fs.add_file(root, File::new(&format!("{} a", u64::MAX)).unwrap());
+        let child = fs.add_directory(Directory::new_from_string("dir b", root).unwrap());
+        fs.add_file(child, File::new("1 c").unwrap());
+
+        let result = fs.get_total_dir_size(root).unwrap_err();
+
+        assert_eq!(result.what(), "total directory size");
+        assert!(fs.get_all_total_sizes().is_err());
+    }
 }
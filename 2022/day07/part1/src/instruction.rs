@@ -1,3 +1,5 @@
+use aoc_common::{ParseError, Tokens};
+
 #[derive(PartialEq, Eq, Debug)]
 pub struct Instruction {
     command_type: Type,
@@ -5,27 +7,27 @@ pub struct Instruction {
 }
 
 impl Instruction {
-    pub fn new(input_line: &str) -> Self {
-        if !input_line.starts_with("$") {
-            panic!("Input [{}] is not a command.", input_line);
+    pub fn new(input_line: &str) -> Result<Self, ParseError> {
+        let mut tokens = Tokens::new(input_line, ' ');
+        let prompt = tokens.expect("a command")?;
+        if prompt.text != "$" {
+            return Err(prompt.unexpected("a command starting with '$'"));
         }
-        let split_string: Vec<&str> = input_line.split(" ").collect();
-        let unparsed_type = split_string.get(1).unwrap();
-        let command_type = Type::from_string(*unparsed_type);
+        let unparsed_type = tokens.expect("a command type")?;
+        let command_type = Type::from_string(unparsed_type.text)
+            .ok_or_else(|| unparsed_type.unexpected("one of cd or ls"))?;
 
         match command_type {
-            Type::LIST => Instruction {
+            Type::LIST => Ok(Instruction {
                 command_type,
                 argument: None,
-            },
+            }),
             Type::NAVIGATE => {
-                let argument = split_string
-                    .get(2)
-                    .expect("Expected argument for command of type [CD] but found none.");
-                Instruction {
+                let argument = tokens.expect("a directory to navigate to")?;
+                Ok(Instruction {
                     command_type,
-                    argument: Option::Some(String::from(*argument)),
-                }
+                    argument: Option::Some(String::from(argument.text)),
+                })
             }
         }
     }
@@ -46,11 +48,11 @@ pub enum Type {
 }
 
 impl Type {
-    fn from_string(input_string: &str) -> Self {
+    fn from_string(input_string: &str) -> Option<Self> {
         match input_string {
-            "cd" => Type::NAVIGATE,
-            "ls" => Type::LIST,
-            _ => panic!("Input command [{}] is not a known type.", input_string),
+            "cd" => Some(Type::NAVIGATE),
+            "ls" => Some(Type::LIST),
+            _ => None,
         }
     }
 }
@@ -58,47 +60,55 @@ impl Type {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_common::ParseErrorKind;
 
     #[test]
-    #[should_panic(expected = "Input [dir a] is not a command.")]
-    fn new_when_not_a_command_panics() {
-        Instruction::new("dir a");
+    fn new_when_not_a_command_returns_error() {
+        let result = Instruction::new("dir a").unwrap_err();
+
+        assert_eq!(result.kind(), ParseErrorKind::UnexpectedToken);
+        assert_eq!(result.found(), "dir");
     }
 
     #[test]
     fn new_when_cd_uses_type_cd() {
-        let result = Instruction::new("$ cd /");
+        let result = Instruction::new("$ cd /").unwrap();
         assert_eq!(result.command_type, Type::NAVIGATE);
     }
 
     #[test]
     fn new_when_ls_uses_type_ls() {
-        let result = Instruction::new("$ ls");
+        let result = Instruction::new("$ ls").unwrap();
         assert_eq!(result.command_type, Type::LIST);
     }
 
     #[test]
-    #[should_panic(expected = "Input command [pwd] is not a known type.")]
-    fn new_when_unknown_type_panics() {
-        Instruction::new("$ pwd");
+    fn new_when_unknown_type_returns_error() {
+        let result = Instruction::new("$ pwd").unwrap_err();
+
+        assert_eq!(result.kind(), ParseErrorKind::UnexpectedToken);
+        assert_eq!(result.span(), 2..5);
+        assert_eq!(result.found(), "pwd");
     }
 
     #[test]
-    #[should_panic(expected = "Expected argument for command of type [CD] but found none.")]
     fn new_when_cd_expect_argument() {
-        Instruction::new("$ cd");
+        let result = Instruction::new("$ cd").unwrap_err();
+
+        assert_eq!(result.kind(), ParseErrorKind::MissingToken);
+        assert_eq!(result.span(), 4..4);
     }
 
     #[test]
     fn new_when_cd_parses_argument() {
-        let result = Instruction::new("$ cd /");
+        let result = Instruction::new("$ cd /").unwrap();
         assert!(result.argument.is_some());
         assert_eq!(result.argument.unwrap(), "/");
     }
 
     #[test]
     fn new_when_ls_dont_parse_arguments() {
-        let result = Instruction::new("$ ls someArgument");
+        let result = Instruction::new("$ ls someArgument").unwrap();
         assert!(result.argument.is_none());
     }
 }
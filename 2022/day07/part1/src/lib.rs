@@ -0,0 +1,55 @@
+mod directory;
+mod file;
+mod file_system;
+mod instruction;
+
+use std::fmt::Display;
+
+use aoc_common::{checked_sum, Error, ParseError, Solution};
+
+pub use crate::directory::Directory;
+pub use crate::file::File;
+pub use crate::file_system::FileSystem;
+pub use crate::instruction::{Instruction, Type};
+
+pub struct Day07;
+
+impl Day07 {
+    pub fn from_lines<S: AsRef<str>>(
+        lines: impl IntoIterator<Item = S>,
+    ) -> Result<FileSystem, ParseError> {
+        let mut fs = FileSystem::new();
+        fs.process_scan(lines)?;
+        Ok(fs)
+    }
+}
+
+impl Solution for Day07 {
+    type Input = FileSystem;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let fs = Day07::from_lines(input.lines())?;
+        Ok(fs)
+    }
+
+    fn part1(fs: &Self::Input) -> Result<impl Display, Error> {
+        let total_sizes = fs.get_all_total_sizes()?;
+
+        let small_sizes = total_sizes.values().copied().filter(|&size| size <= 100000);
+        Ok(checked_sum(small_sizes, "sum of small directory sizes")?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = include_str!("../input_test.txt");
+
+    #[test]
+    fn part1_example() {
+        let fs = Day07::parse(INPUT).unwrap();
+
+        assert_eq!(Day07::part1(&fs).unwrap().to_string(), "95437");
+    }
+}
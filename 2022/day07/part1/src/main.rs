@@ -1,26 +1,30 @@
-use file_system::FileSystem;
+use std::process::ExitCode;
 
-use util::read_all_lines;
+use aoc_common::{exit_code, DayArgs, Error, Format, Report, Solution};
+use clap::Parser;
+use day07_part1::{Day07, FileSystem};
 
-mod directory;
-mod file;
-mod file_system;
-mod instruction;
-mod util;
+fn main() -> ExitCode {
+    exit_code(run())
+}
 
-fn main() {
-    let lines = read_all_lines("./input.txt");
+fn run() -> Result<(), Error> {
+    let args = DayArgs::parse();
+    if args.format == Format::Json {
+        // The report hashes the whole input, so it is read at once.
+        let input = args.input.load()?;
+        println!("{}", Report::part1::<Day07>(2022, 7, &input)?.to_json());
+        return Ok(());
+    }
 
     let mut fs = FileSystem::new();
-    fs.process_scan(lines);
-
-    let total_sizes = fs.get_all_total_sizes();
+    args.input
+        .lines()?
+        .for_each_line(|line| fs.process_line(line))?;
 
-    let result: i32 = total_sizes
-        .iter()
-        .filter(|&(_, &size)| size <= 100000)
-        .map(|entry| entry.1)
-        .sum();
+    let result = Day07::part1(&fs)?;
 
     println!("Total sum: {result}");
+
+    Ok(())
 }
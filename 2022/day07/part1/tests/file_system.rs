@@ -0,0 +1,31 @@
+use day07_part1::{Day07, FileSystem, Instruction, Type};
+
+const LOG: &str = include_str!("../input_test.txt");
+
+#[test]
+fn process_scan_totals_nested_directories() {
+    let mut fs = FileSystem::new();
+
+    fs.process_scan(LOG.lines()).unwrap();
+
+    let sizes = fs.get_all_total_sizes().unwrap();
+    assert_eq!(sizes["e"], 584);
+    assert_eq!(sizes["a"], 94853);
+    assert_eq!(sizes["d"], 24933642);
+}
+
+#[test]
+fn from_lines_matches_process_scan() {
+    let fs = Day07::from_lines(LOG.lines()).unwrap();
+
+    let a = fs.get_index_for_name("a");
+    assert_eq!(fs.get_total_dir_size(a).unwrap(), 94853);
+}
+
+#[test]
+fn instruction_parses_navigation() {
+    let instruction = Instruction::new("$ cd a").unwrap();
+
+    assert_eq!(instruction.command_type(), &Type::NAVIGATE);
+    assert_eq!(instruction.argument().map(String::as_str), Some("a"));
+}
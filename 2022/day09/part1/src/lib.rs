@@ -0,0 +1,98 @@
+mod rope;
+
+use std::fmt::Display;
+
+use aoc_common::{parse_lines, Direction, Error, ParseError, Solution, Tokens};
+
+pub use crate::rope::Rope;
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<(Direction, i32)>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let commands = parse_lines(input, extract_command_and_amount)?;
+        Ok(commands)
+    }
+
+    fn part1(commands: &Self::Input) -> Result<impl Display, Error> {
+        let mut rope = Rope::new();
+
+        for (direction, nb_steps) in commands {
+            rope.process_command(*direction, *nb_steps);
+        }
+
+        Ok(rope.get_nb_unique_visited_locations())
+    }
+}
+
+impl Day09 {
+    /// Moves `rope` by the command on `line`.
+    pub fn apply_line(rope: &mut Rope, line: &str) -> Result<(), ParseError> {
+        let (direction, nb_steps) = extract_command_and_amount(line)?;
+        rope.process_command(direction, nb_steps);
+        Ok(())
+    }
+}
+
+fn extract_command_and_amount(input: &str) -> Result<(Direction, i32), ParseError> {
+    let mut tokens = Tokens::new(input, ' ');
+
+    let command = tokens.expect("a direction")?;
+    let mut letters = command.text.chars();
+    let direction = match (
+        letters.next().and_then(Direction::from_letter),
+        letters.next(),
+    ) {
+        (Some(direction), None) => direction,
+        _ => return Err(command.unexpected("one of R, L, U or D")),
+    };
+    let nb_steps: i32 = tokens
+        .expect("a number of steps")?
+        .parse_number("a number of steps")?;
+    tokens.finish("end of line")?;
+
+    Ok((direction, nb_steps))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::ParseErrorKind;
+
+    const INPUT: &str = include_str!("../input_test.txt");
+
+    #[test]
+    fn part1_example() {
+        let commands = Day09::parse(INPUT).unwrap();
+
+        assert_eq!(Day09::part1(&commands).unwrap().to_string(), "13");
+    }
+
+    #[test]
+    fn extract_command_and_amount_parses_command() {
+        let result = extract_command_and_amount("U 4").unwrap();
+
+        assert_eq!(result, (Direction::Up, 4));
+    }
+
+    #[test]
+    fn extract_command_and_amount_when_unknown_direction_returns_error() {
+        let result = extract_command_and_amount("X 4").unwrap_err();
+
+        assert_eq!(result.kind(), ParseErrorKind::UnexpectedToken);
+        assert_eq!(result.span(), 0..1);
+    }
+
+    #[test]
+    fn parse_when_steps_invalid_reports_line() {
+        let Err(Error::Parse(result)) = Day09::parse("R 4\nU four") else {
+            panic!("expected a parse error");
+        };
+
+        assert_eq!(result.kind(), ParseErrorKind::InvalidNumber);
+        assert_eq!(result.line(), 2);
+        assert_eq!(result.span(), 2..6);
+    }
+}
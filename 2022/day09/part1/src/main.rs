@@ -1,34 +1,48 @@
-use rope::Rope;
-use util::read_all_lines;
+use std::process::ExitCode;
 
-mod location;
-mod rope;
-mod util;
+use aoc_common::{exit_code, DayArgs, Error, Format, Report};
+use clap::Parser;
+use day09_part1::{Day09, Rope};
 
-fn main() {
-    let lines = read_all_lines("input.txt");
+/// Counts the locations the tail of a rope visits.
+#[derive(Parser)]
+struct Args {
+    #[command(flatten)]
+    day: DayArgs,
+
+    /// Draw the locations the tail visited instead of counting them
+    #[arg(long, conflicts_with = "format")]
+    render: bool,
+}
+
+fn main() -> ExitCode {
+    exit_code(run())
+}
+
+fn run() -> Result<(), Error> {
+    let args = Args::parse();
+    let render = args.render;
+    let args = args.day;
+    if args.format == Format::Json {
+        // The report hashes the whole input, so it is read at once.
+        let input = args.input.load()?;
+        println!("{}", Report::part1::<Day09>(2022, 9, &input)?.to_json());
+        return Ok(());
+    }
 
     let mut rope = Rope::new();
+    args.input
+        .lines()?
+        .for_each_line(|line| Day09::apply_line(&mut rope, line))?;
 
-    for line in lines {
-        let (command, nb_steps) = extract_command_and_amount(&line);
-        rope.process_command(command, nb_steps);
+    if render {
+        print!("{}", rope.render_visited_locations());
+        return Ok(());
     }
 
     let total_visited_locations = rope.get_nb_unique_visited_locations();
 
     println!("The tail visited a total of {total_visited_locations} unique locations");
-}
-
-fn extract_command_and_amount(input: &str) -> (&str, i32) {
-    let split: Vec<&str> = input.split(" ").collect();
-
-    let command = split.get(0).unwrap();
-    let nb_steps: i32 = split
-        .get(1)
-        .unwrap()
-        .parse::<i32>()
-        .expect("error parsing 'nb_steps'");
 
-    (command, nb_steps)
+    Ok(())
 }
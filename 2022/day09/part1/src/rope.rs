@@ -1,30 +1,30 @@
-use std::{collections::HashSet, hash::Hash};
-
-use crate::location::Location;
+use aoc_common::{Direction, Point, SparseGrid};
 
+/// A rope on a plane where `y` grows upwards, as in the puzzle, unlike grid rows.
 pub struct Rope {
-    head: Location,
-    tail: Location,
-    visited_locations: HashSet<Location>,
+    head: Point,
+    tail: Point,
+    visited_locations: SparseGrid<()>,
 }
 
 impl Rope {
     pub fn new() -> Self {
-        let mut visited_locations = HashSet::new();
-        visited_locations.insert(Location::new());
+        let mut visited_locations = SparseGrid::new();
+        visited_locations.insert(Point::ORIGIN, ());
 
         Rope {
-            head: Location::new(),
-            tail: Location::new(),
+            head: Point::ORIGIN,
+            tail: Point::ORIGIN,
             visited_locations,
         }
     }
 
-    pub fn from_start(head: impl Into<Location>, tail: impl Into<Location>) -> Self {
+    #[cfg(test)]
+    pub fn from_start(head: impl Into<Point>, tail: impl Into<Point>) -> Self {
         let tail = tail.into();
 
-        let mut visited_locations = HashSet::new();
-        visited_locations.insert(tail);
+        let mut visited_locations = SparseGrid::new();
+        visited_locations.insert(tail, ());
 
         Rope {
             head: head.into(),
@@ -33,17 +33,17 @@ impl Rope {
         }
     }
 
-    pub fn tail(&self) -> Location {
+    pub fn tail(&self) -> Point {
         self.tail
     }
 
-    pub fn process_command(&mut self, command: &str, nb_steps: i32) {
-        match command {
-            "R" => self.move_right(nb_steps),
-            "L" => self.move_left(nb_steps),
-            "U" => self.move_up(nb_steps),
-            "D" => self.move_down(nb_steps),
-            _ => panic!("Invalid command [{}] encountered", command),
+    pub fn process_command(&mut self, direction: Direction, nb_steps: i32) {
+        for _ in 0..nb_steps {
+            self.head += step(direction);
+            if self.tail.chebyshev(self.head) > 1 {
+                self.tail = self.tail.step_toward(self.head);
+            }
+            self.visited_locations.insert(self.tail, ());
         }
     }
 
@@ -51,71 +51,28 @@ impl Rope {
         self.visited_locations.len()
     }
 
-    fn move_right(&mut self, nb_steps: i32) {
-        for _ in 0..nb_steps {
-            if self.head.is_diagonal_from(&self.tail)
-                && self.head.moves_further_right_from(&self.tail)
-            {
-                self.tail = self.head.clone();
-                self.head.move_right();
-            } else {
-                self.head.move_right();
-                if self.tail.horizontal_distance_between(&self.head) > 1 {
-                    self.tail.move_right();
-                }
-            }
-            self.visited_locations.insert(self.tail);
-        }
-    }
-
-    fn move_left(&mut self, nb_steps: i32) {
-        for _ in 0..nb_steps {
-            if self.head.is_diagonal_from(&self.tail)
-                && self.head.moves_further_left_from(&self.tail)
-            {
-                self.tail = self.head.clone();
-                self.head.move_left();
-            } else {
-                self.head.move_left();
-                if self.tail.horizontal_distance_between(&self.head) > 1 {
-                    self.tail.move_left();
-                }
-            }
-            self.visited_locations.insert(self.tail);
-        }
+    /// Draws the locations the tail visited as `#`, with the start marked `s` and up on top.
+    pub fn render_visited_locations(&self) -> String {
+        // Rendering puts the lowest y on the first row, so y is flipped to draw up on top.
+        let mut cells: SparseGrid<char> = self
+            .visited_locations
+            .iter()
+            .map(|(location, _)| (Point::new(location.x(), -location.y()), '#'))
+            .collect();
+        cells.insert(Point::ORIGIN, 's');
+        cells.render('.', |cell| *cell)
     }
+}
 
-    fn move_up(&mut self, nb_steps: i32) {
-        for _ in 0..nb_steps {
-            if self.head.is_diagonal_from(&self.tail) && self.head.moves_further_up_from(&self.tail)
-            {
-                self.tail = self.head.clone();
-                self.head.move_up();
-            } else {
-                self.head.move_up();
-                if self.tail.vertical_distance_between(&self.head) > 1 {
-                    self.tail.move_up();
-                }
-            }
-            self.visited_locations.insert(self.tail);
-        }
-    }
+/// The offset moving one step in `direction`, with `Up` increasing `y`.
+fn step(direction: Direction) -> Point {
+    let offset = direction.offset();
+    Point::new(offset.x(), -offset.y())
+}
 
-    fn move_down(&mut self, nb_steps: i32) {
-        for _ in 0..nb_steps {
-            if self.head.is_diagonal_from(&self.tail)
-                && self.head.moves_further_down_from(&self.tail)
-            {
-                self.tail = self.head.clone();
-                self.head.move_down();
-            } else {
-                self.head.move_down();
-                if self.tail.vertical_distance_between(&self.head) > 1 {
-                    self.tail.move_down();
-                }
-            }
-            self.visited_locations.insert(self.tail);
-        }
+impl Default for Rope {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
@@ -124,13 +81,23 @@ mod tests {
     use super::*;
     #[test]
     fn new_starts_head_and_tail_at_origin() {
-        let origin = Location::new();
+        let origin = Point::ORIGIN;
 
         let result = Rope::new();
 
         assert_eq!(result.head, origin);
         assert_eq!(result.tail, origin);
     }
+
+    #[test]
+    fn render_visited_locations_marks_start() {
+        let mut rope = Rope::new();
+
+        rope.process_command(Direction::Right, 3);
+        rope.process_command(Direction::Up, 2);
+
+        assert_eq!(rope.render_visited_locations(), "...#\ns##.\n");
+    }
 }
 
 #[cfg(test)]
@@ -140,9 +107,9 @@ mod tests_move_right {
     #[test]
     fn move_by_one_when_at_same_starting_position_tail_doesnt_move() {
         let mut rope = Rope::new();
-        let initial_tail = rope.tail.clone();
+        let initial_tail = rope.tail;
 
-        rope.move_right(1);
+        rope.process_command(Direction::Right, 1);
 
         assert_eq!(rope.tail, initial_tail);
     }
@@ -151,49 +118,49 @@ mod tests_move_right {
     fn move_by_one_head_x_one_higher() {
         let mut rope = Rope::new();
 
-        rope.move_right(1);
+        rope.process_command(Direction::Right, 1);
 
-        assert_eq!(rope.head, Location::new_from_coordinates(1, 0));
+        assert_eq!(rope.head, Point::new(1, 0));
     }
 
     #[test]
     fn move_right_by_two_head_two_higher() {
         let mut rope = Rope::new();
 
-        rope.move_right(2);
+        rope.process_command(Direction::Right, 2);
 
-        assert_eq!(rope.head, Location::new_from_coordinates(2, 0));
+        assert_eq!(rope.head, Point::new(2, 0));
     }
 
     #[test]
     fn move_by_two_initial_position_equal_tail_moves_right() {
         let mut rope = Rope::new();
 
-        rope.move_right(2);
+        rope.process_command(Direction::Right, 2);
 
-        assert_eq!(rope.tail, Location::new_from_coordinates(1, 0));
+        assert_eq!(rope.tail, Point::new(1, 0));
     }
 
     #[test]
     fn when_diagonal_and_moves_further_tail_follows() {
-        let initial_head = Location::new_from_coordinates(1, 1);
-        let initial_tail = Location::new_from_coordinates(0, 0);
+        let initial_head = Point::new(1, 1);
+        let initial_tail = Point::new(0, 0);
 
         let mut rope = Rope::from_start(initial_head, initial_tail);
 
-        rope.move_right(1);
+        rope.process_command(Direction::Right, 1);
 
-        assert_eq!(rope.tail, Location::new_from_coordinates(1, 1));
+        assert_eq!(rope.tail, Point::new(1, 1));
     }
 
     #[test]
     fn when_diagonal_and_moves_closer_tail_doesnt_move() {
-        let initial_head = Location::new_from_coordinates(0, 0);
-        let initial_tail = Location::new_from_coordinates(1, 1);
+        let initial_head = Point::new(0, 0);
+        let initial_tail = Point::new(1, 1);
 
         let mut rope = Rope::from_start(initial_head, initial_tail);
 
-        rope.move_right(1);
+        rope.process_command(Direction::Right, 1);
 
         assert_eq!(rope.tail, initial_tail);
     }
@@ -206,9 +173,9 @@ mod tests_move_left {
     #[test]
     fn move_by_one_when_at_same_starting_position_tail_doesnt_move() {
         let mut rope = Rope::new();
-        let initial_tail = rope.tail.clone();
+        let initial_tail = rope.tail;
 
-        rope.move_left(1);
+        rope.process_command(Direction::Left, 1);
 
         assert_eq!(rope.tail, initial_tail);
     }
@@ -217,50 +184,50 @@ mod tests_move_left {
     fn move_by_one_head_x_one_lower() {
         let mut rope = Rope::new();
 
-        rope.move_left(1);
+        rope.process_command(Direction::Left, 1);
 
-        assert_eq!(rope.head, Location::new_from_coordinates(-1, 0));
+        assert_eq!(rope.head, Point::new(-1, 0));
     }
 
     #[test]
     fn move_by_two_head_two_lower() {
         let mut rope = Rope::new();
 
-        rope.move_left(2);
+        rope.process_command(Direction::Left, 2);
 
-        assert_eq!(rope.head, Location::new_from_coordinates(-2, 0));
+        assert_eq!(rope.head, Point::new(-2, 0));
     }
 
     #[test]
     fn move_by_two_initial_position_equal_tail_moves_left() {
         let mut rope = Rope::new();
-        let expected = rope.tail.clone().move_left();
+        let expected = rope.tail + Direction::Left.offset();
 
-        rope.move_left(2);
+        rope.process_command(Direction::Left, 2);
 
         assert_eq!(rope.tail, expected);
     }
 
     #[test]
     fn when_diagonal_and_moves_further_tail_follows() {
-        let initial_head = Location::new_from_coordinates(0, 0);
-        let initial_tail = Location::new_from_coordinates(1, 1);
+        let initial_head = Point::new(0, 0);
+        let initial_tail = Point::new(1, 1);
 
         let mut rope = Rope::from_start(initial_head, initial_tail);
 
-        rope.move_left(1);
+        rope.process_command(Direction::Left, 1);
 
-        assert_eq!(rope.tail, Location::new_from_coordinates(0, 0));
+        assert_eq!(rope.tail, Point::new(0, 0));
     }
 
     #[test]
     fn when_diagonal_and_moves_closer_tail_doesnt_move() {
-        let initial_head = Location::new_from_coordinates(1, 1);
-        let initial_tail = Location::new_from_coordinates(0, 0);
+        let initial_head = Point::new(1, 1);
+        let initial_tail = Point::new(0, 0);
 
         let mut rope = Rope::from_start(initial_head, initial_tail);
 
-        rope.move_left(1);
+        rope.process_command(Direction::Left, 1);
 
         assert_eq!(rope.tail, initial_tail);
     }
@@ -273,9 +240,9 @@ mod tests_move_up {
     #[test]
     fn move_up_by_one_when_at_same_starting_position_tail_doesnt_move() {
         let mut rope = Rope::new();
-        let initial_tail = rope.tail.clone();
+        let initial_tail = rope.tail;
 
-        rope.move_up(1);
+        rope.process_command(Direction::Up, 1);
 
         assert_eq!(rope.tail, initial_tail);
     }
@@ -284,49 +251,49 @@ mod tests_move_up {
     fn move_up_by_one_head_y_one_higher() {
         let mut rope = Rope::new();
 
-        rope.move_up(1);
+        rope.process_command(Direction::Up, 1);
 
-        assert_eq!(rope.head, Location::new_from_coordinates(0, 1));
+        assert_eq!(rope.head, Point::new(0, 1));
     }
 
     #[test]
     fn move_up_by_two_head_two_higher() {
         let mut rope = Rope::new();
 
-        rope.move_up(2);
+        rope.process_command(Direction::Up, 2);
 
-        assert_eq!(rope.head, Location::new_from_coordinates(0, 2));
+        assert_eq!(rope.head, Point::new(0, 2));
     }
 
     #[test]
     fn move_up_by_two_initial_position_equal_tail_moves_up() {
         let mut rope = Rope::new();
 
-        rope.move_up(2);
+        rope.process_command(Direction::Up, 2);
 
-        assert_eq!(rope.tail, Location::new_from_coordinates(0, 1));
+        assert_eq!(rope.tail, Point::new(0, 1));
     }
 
     #[test]
     fn when_diagonal_and_moves_further_tail_follows() {
-        let initial_head = Location::new_from_coordinates(1, 1);
-        let initial_tail = Location::new_from_coordinates(0, 0);
+        let initial_head = Point::new(1, 1);
+        let initial_tail = Point::new(0, 0);
 
         let mut rope = Rope::from_start(initial_head, initial_tail);
 
-        rope.move_up(1);
+        rope.process_command(Direction::Up, 1);
 
-        assert_eq!(rope.tail, Location::new_from_coordinates(1, 1));
+        assert_eq!(rope.tail, Point::new(1, 1));
     }
 
     #[test]
     fn when_diagonal_and_moves_closer_tail_doesnt_move() {
-        let initial_head = Location::new_from_coordinates(0, 0);
-        let initial_tail = Location::new_from_coordinates(1, 1);
+        let initial_head = Point::new(0, 0);
+        let initial_tail = Point::new(1, 1);
 
         let mut rope = Rope::from_start(initial_head, initial_tail);
 
-        rope.move_up(1);
+        rope.process_command(Direction::Up, 1);
 
         assert_eq!(rope.tail, initial_tail);
     }
@@ -339,9 +306,9 @@ mod tests_move_down {
     #[test]
     fn move_down_by_one_when_at_same_starting_position_tail_doesnt_move() {
         let mut rope = Rope::new();
-        let initial_tail = rope.tail.clone();
+        let initial_tail = rope.tail;
 
-        rope.move_down(1);
+        rope.process_command(Direction::Down, 1);
 
         assert_eq!(rope.tail, initial_tail);
     }
@@ -350,49 +317,49 @@ mod tests_move_down {
     fn move_down_by_one_head_y_one_lower() {
         let mut rope = Rope::new();
 
-        rope.move_down(1);
+        rope.process_command(Direction::Down, 1);
 
-        assert_eq!(rope.head, Location::new_from_coordinates(0, -1));
+        assert_eq!(rope.head, Point::new(0, -1));
     }
 
     #[test]
     fn move_down_by_two_head_two_lower() {
         let mut rope = Rope::new();
 
-        rope.move_down(2);
+        rope.process_command(Direction::Down, 2);
 
-        assert_eq!(rope.head, Location::new_from_coordinates(0, -2));
+        assert_eq!(rope.head, Point::new(0, -2));
     }
 
     #[test]
     fn move_down_by_two_initial_position_equal_tail_moves_down() {
         let mut rope = Rope::new();
 
-        rope.move_down(2);
+        rope.process_command(Direction::Down, 2);
 
-        assert_eq!(rope.tail, Location::new_from_coordinates(0, -1));
+        assert_eq!(rope.tail, Point::new(0, -1));
     }
 
     #[test]
     fn when_diagonal_and_moves_further_tail_follows() {
-        let initial_head = Location::new_from_coordinates(0, 0);
-        let initial_tail = Location::new_from_coordinates(1, 1);
+        let initial_head = Point::new(0, 0);
+        let initial_tail = Point::new(1, 1);
 
         let mut rope = Rope::from_start(initial_head, initial_tail);
 
-        rope.move_down(1);
+        rope.process_command(Direction::Down, 1);
 
-        assert_eq!(rope.tail, Location::new_from_coordinates(0, 0));
+        assert_eq!(rope.tail, Point::new(0, 0));
     }
 
     #[test]
     fn when_diagonal_and_moves_closer_tail_doesnt_move() {
-        let initial_head = Location::new_from_coordinates(1, 1);
-        let initial_tail = Location::new_from_coordinates(0, 0);
+        let initial_head = Point::new(1, 1);
+        let initial_tail = Point::new(0, 0);
 
         let mut rope = Rope::from_start(initial_head, initial_tail);
 
-        rope.move_down(1);
+        rope.process_command(Direction::Down, 1);
 
         assert_eq!(rope.tail, initial_tail);
     }
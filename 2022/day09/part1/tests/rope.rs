@@ -0,0 +1,21 @@
+use aoc_common::{Direction, Point};
+use day09_part1::Rope;
+
+#[test]
+fn rope_tail_follows_the_head() {
+    let mut rope = Rope::new();
+
+    rope.process_command(Direction::Right, 4);
+    rope.process_command(Direction::Up, 2);
+
+    assert_eq!(rope.tail(), Point::new(4, 1));
+    assert_eq!(rope.get_nb_unique_visited_locations(), 5);
+}
+
+#[test]
+fn rope_starts_at_origin() {
+    let rope = Rope::default();
+
+    assert_eq!(rope.tail(), Point::ORIGIN);
+    assert_eq!(rope.get_nb_unique_visited_locations(), 1);
+}
@@ -0,0 +1,98 @@
+use std::any::type_name;
+use std::fmt;
+
+/// A total that no longer fits in the type accumulating it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Overflow {
+    what: &'static str,
+    type_name: &'static str,
+}
+
+impl Overflow {
+    pub fn new<T>(what: &'static str) -> Self {
+        Overflow {
+            what,
+            type_name: type_name::<T>(),
+        }
+    }
+
+    /// What was being added up, like "total calories".
+    pub fn what(&self) -> &'static str {
+        self.what
+    }
+}
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} overflowed {}", self.what, self.type_name)
+    }
+}
+
+impl std::error::Error for Overflow {}
+
+/// Integer types [`checked_sum`] can accumulate into.
+pub trait CheckedAdd: Copy {
+    const ZERO: Self;
+
+    fn checked_add(self, other: Self) -> Option<Self>;
+}
+
+macro_rules! impl_checked_add {
+    ($($type:ty),*) => {
+        $(
+            impl CheckedAdd for $type {
+                const ZERO: Self = 0;
+
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    <$type>::checked_add(self, other)
+                }
+            }
+        )*
+    };
+}
+
+impl_checked_add!(i32, i64, i128, u32, u64, u128, usize);
+
+/// Adds up `items`, failing instead of wrapping when the total does not fit in `T`.
+///
+/// `what` names the total in the error.
+pub fn checked_sum<T: CheckedAdd>(
+    items: impl IntoIterator<Item = T>,
+    what: &'static str,
+) -> Result<T, Overflow> {
+    items.into_iter().try_fold(T::ZERO, |total, item| {
+        total
+            .checked_add(item)
+            .ok_or_else(|| Overflow::new::<T>(what))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checked_sum_adds_up_to_the_maximum() {
+        assert_eq!(checked_sum([i64::MAX - 1, 1], "total"), Ok(i64::MAX));
+        assert_eq!(checked_sum([u64::MAX, 0], "total"), Ok(u64::MAX));
+        assert_eq!(checked_sum(Vec::<i32>::new(), "total"), Ok(0));
+    }
+
+    #[test]
+    fn checked_sum_when_past_maximum_returns_overflow() {
+        let result = checked_sum([i32::MAX, 1], "total score").unwrap_err();
+
+        assert_eq!(result.what(), "total score");
+        assert_eq!(result.to_string(), "total score overflowed i32");
+    }
+
+    #[test]
+    fn checked_sum_when_past_minimum_returns_overflow() {
+        assert!(checked_sum([i64::MIN, -1], "total").is_err());
+    }
+
+    #[test]
+    fn checked_sum_when_unsigned_past_maximum_returns_overflow() {
+        assert!(checked_sum([u64::MAX, 1], "total size").is_err());
+    }
+}
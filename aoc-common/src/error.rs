@@ -0,0 +1,71 @@
+use std::fmt;
+use std::process::ExitCode;
+
+use crate::{InputError, Overflow, ParseError};
+
+#[derive(Debug)]
+pub enum Error {
+    Input(InputError),
+    Parse(ParseError),
+    /// A total grew past what its type can hold while solving.
+    Overflow(Overflow),
+}
+
+impl Error {
+    /// Moves a parse error down by `lines`, leaving other errors where they are.
+    pub fn offset_lines(self, lines: usize) -> Self {
+        match self {
+            Error::Parse(error) => Error::Parse(error.offset_lines(lines)),
+            error => error,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Input(error) => write!(f, "{error}"),
+            Error::Parse(error) => write!(f, "could not parse input at {error}"),
+            Error::Overflow(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Input(error) => Some(error),
+            Error::Parse(error) => Some(error),
+            Error::Overflow(error) => Some(error),
+        }
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(error: ParseError) -> Self {
+        Error::Parse(error)
+    }
+}
+
+impl From<Overflow> for Error {
+    fn from(error: Overflow) -> Self {
+        Error::Overflow(error)
+    }
+}
+
+impl From<InputError> for Error {
+    fn from(error: InputError) -> Self {
+        Error::Input(error)
+    }
+}
+
+/// Reports a failed run on stderr and turns the result into the process exit code.
+pub fn exit_code(result: Result<(), Error>) -> ExitCode {
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("error: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
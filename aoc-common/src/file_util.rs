@@ -0,0 +1,156 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::io::prelude::*;
+use std::path::{Path, PathBuf};
+
+use crate::LineReader;
+
+/// Failure to load a puzzle input from disk.
+#[derive(Debug)]
+pub struct InputError {
+    path: PathBuf,
+    line: Option<usize>,
+    source: io::Error,
+}
+
+impl InputError {
+    pub(crate) fn new(path: &Path, line: Option<usize>, source: io::Error) -> Self {
+        InputError {
+            path: path.to_path_buf(),
+            line,
+            source,
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// 1-based line on which decoding failed, if the file could be opened.
+    pub fn line(&self) -> Option<usize> {
+        self.line
+    }
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.line {
+            Some(line) => write!(
+                f,
+                "could not read {} at line {}: {}",
+                self.path.display(),
+                line,
+                self.source
+            ),
+            None => write!(f, "could not read {}: {}", self.path.display(), self.source),
+        }
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+/// Reads every line up front, [`LineReader`] reads them one at a time instead.
+pub fn read_all_lines(filename: impl AsRef<Path>) -> Result<Vec<String>, InputError> {
+    LineReader::open(filename)?.collect()
+}
+
+pub fn read_input(filename: impl AsRef<Path>) -> Result<String, InputError> {
+    let path = filename.as_ref();
+    let bytes = fs::read(path).map_err(|error| InputError::new(path, None, error))?;
+
+    decode(path, bytes)
+}
+
+/// Reads all of standard input, reporting errors against the path `-`.
+pub fn read_stdin() -> Result<String, InputError> {
+    let path = Path::new("-");
+    let mut bytes = Vec::new();
+    io::stdin()
+        .read_to_end(&mut bytes)
+        .map_err(|error| InputError::new(path, None, error))?;
+
+    decode(path, bytes)
+}
+
+fn decode(path: &Path, bytes: Vec<u8>) -> Result<String, InputError> {
+    String::from_utf8(bytes).map_err(|error| {
+        let valid = &error.as_bytes()[..error.utf8_error().valid_up_to()];
+        let line = valid.iter().filter(|&&byte| byte == b'\n').count() + 1;
+        InputError::new(
+            path,
+            Some(line),
+            io::Error::new(io::ErrorKind::InvalidData, error.utf8_error()),
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    fn write_temp_file(name: &str, content: &[u8]) -> PathBuf {
+        let path = env::temp_dir().join(format!("aoc-common-{}-{name}", std::process::id()));
+        fs::write(&path, content).unwrap();
+        path
+    }
+
+    #[test]
+    fn read_all_lines_returns_lines() {
+        let path = write_temp_file("lines.txt", b"a\nb\n\nc");
+
+        let result = read_all_lines(&path).unwrap();
+
+        assert_eq!(result, vec!["a", "b", "", "c"]);
+    }
+
+    #[test]
+    fn read_all_lines_when_missing_reports_path() {
+        let path = env::temp_dir().join("aoc-common-does-not-exist.txt");
+
+        let result = read_all_lines(&path).unwrap_err();
+
+        assert_eq!(result.path(), path);
+        assert_eq!(result.line(), None);
+        assert_eq!(result.source.kind(), io::ErrorKind::NotFound);
+    }
+
+    #[test]
+    fn read_all_lines_when_invalid_utf8_reports_line() {
+        let path = write_temp_file("invalid-lines.txt", b"a\nb\n\xff\nd");
+
+        let result = read_all_lines(&path).unwrap_err();
+
+        assert_eq!(result.line(), Some(3));
+        assert_eq!(result.source.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn read_input_when_invalid_utf8_reports_line() {
+        let path = write_temp_file("invalid-input.txt", b"a\nb\nc\x80d\n");
+
+        let result = read_input(&path).unwrap_err();
+
+        assert_eq!(result.line(), Some(3));
+        assert_eq!(result.source.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn display_includes_path_and_line() {
+        let error = InputError::new(
+            Path::new("input.txt"),
+            Some(4),
+            io::Error::new(io::ErrorKind::InvalidData, "bad byte"),
+        );
+
+        assert_eq!(
+            error.to_string(),
+            "could not read input.txt at line 4: bad byte"
+        );
+    }
+}
@@ -0,0 +1,382 @@
+use std::ops::{Add, AddAssign, Neg, Sub, SubAssign};
+
+/// A position or offset on a 2D grid.
+///
+/// `y` grows downwards, like row numbers in a puzzle input, so `Direction::Up` decreases it.
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Hash, Default, PartialOrd, Ord)]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point { x: 0, y: 0 };
+
+    pub const fn new(x: i32, y: i32) -> Self {
+        Point { x, y }
+    }
+
+    pub fn x(&self) -> i32 {
+        self.x
+    }
+
+    pub fn y(&self) -> i32 {
+        self.y
+    }
+
+    pub fn manhattan(&self, other: Point) -> i32 {
+        (other.x - self.x).abs() + (other.y - self.y).abs()
+    }
+
+    /// The number of king moves between both points, diagonal steps counting as one.
+    pub fn chebyshev(&self, other: Point) -> i32 {
+        (other.x - self.x).abs().max((other.y - self.y).abs())
+    }
+
+    /// Each coordinate replaced by its sign, turning any offset into a single step.
+    pub fn signum(&self) -> Point {
+        Point::new(self.x.signum(), self.y.signum())
+    }
+
+    /// The point one step closer to `target`, moving diagonally when both coordinates differ.
+    pub fn step_toward(&self, target: Point) -> Point {
+        *self + (target - *self).signum()
+    }
+
+    /// Rotated a quarter turn clockwise around the origin.
+    pub fn rotate_right(&self) -> Point {
+        Point::new(-self.y, self.x)
+    }
+
+    /// Rotated a quarter turn counterclockwise around the origin.
+    pub fn rotate_left(&self) -> Point {
+        Point::new(self.y, -self.x)
+    }
+
+    pub fn neighbour(&self, direction: Direction) -> Point {
+        *self + direction.offset()
+    }
+
+    /// The points up, right, down and left of this one.
+    pub fn neighbours4(&self) -> impl Iterator<Item = Point> {
+        let point = *self;
+        Direction::CARDINAL
+            .into_iter()
+            .map(move |direction| point.neighbour(direction))
+    }
+
+    /// The eight points around this one, diagonals included, clockwise from up.
+    pub fn neighbours8(&self) -> impl Iterator<Item = Point> {
+        let point = *self;
+        Direction::ALL
+            .into_iter()
+            .map(move |direction| point.neighbour(direction))
+    }
+}
+
+impl From<(i32, i32)> for Point {
+    fn from((x, y): (i32, i32)) -> Self {
+        Point::new(x, y)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Point) {
+        *self = *self - other;
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Hash)]
+pub enum Direction {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction {
+    /// The 4-neighbourhood, clockwise from up.
+    pub const CARDINAL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// The 8-neighbourhood, clockwise from up.
+    pub const ALL: [Direction; 8] = [
+        Direction::Up,
+        Direction::UpRight,
+        Direction::Right,
+        Direction::DownRight,
+        Direction::Down,
+        Direction::DownLeft,
+        Direction::Left,
+        Direction::UpLeft,
+    ];
+
+    /// Reads the `U`, `D`, `L` and `R` letters puzzles use for the cardinal directions.
+    pub fn from_letter(letter: char) -> Option<Direction> {
+        match letter {
+            'U' => Some(Direction::Up),
+            'D' => Some(Direction::Down),
+            'L' => Some(Direction::Left),
+            'R' => Some(Direction::Right),
+            _ => None,
+        }
+    }
+
+    pub fn offset(&self) -> Point {
+        match self {
+            Direction::Up => Point::new(0, -1),
+            Direction::UpRight => Point::new(1, -1),
+            Direction::Right => Point::new(1, 0),
+            Direction::DownRight => Point::new(1, 1),
+            Direction::Down => Point::new(0, 1),
+            Direction::DownLeft => Point::new(-1, 1),
+            Direction::Left => Point::new(-1, 0),
+            Direction::UpLeft => Point::new(-1, -1),
+        }
+    }
+
+    pub fn is_diagonal(&self) -> bool {
+        !Direction::CARDINAL.contains(self)
+    }
+
+    /// The direction a quarter turn clockwise.
+    pub fn turn_right(&self) -> Direction {
+        self.rotate(2)
+    }
+
+    /// The direction a quarter turn counterclockwise.
+    pub fn turn_left(&self) -> Direction {
+        self.rotate(6)
+    }
+
+    pub fn opposite(&self) -> Direction {
+        self.rotate(4)
+    }
+
+    /// Turns clockwise by `eighths` of a full turn.
+    fn rotate(&self, eighths: usize) -> Direction {
+        let index = Direction::ALL
+            .iter()
+            .position(|direction| direction == self)
+            .expect("ALL holds every direction");
+        Direction::ALL[(index + eighths) % Direction::ALL.len()]
+    }
+}
+
+/// The smallest axis-aligned rectangle holding a set of points, both corners included.
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Hash)]
+pub struct BoundingBox {
+    min: Point,
+    max: Point,
+}
+
+impl BoundingBox {
+    pub fn new(point: Point) -> Self {
+        BoundingBox {
+            min: point,
+            max: point,
+        }
+    }
+
+    /// `None` when there are no points.
+    pub fn from_points(points: impl IntoIterator<Item = Point>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let mut bounds = BoundingBox::new(points.next()?);
+        for point in points {
+            bounds.include(point);
+        }
+        Some(bounds)
+    }
+
+    pub fn min(&self) -> Point {
+        self.min
+    }
+
+    pub fn max(&self) -> Point {
+        self.max
+    }
+
+    /// Grows the box just enough to hold `point`.
+    pub fn include(&mut self, point: Point) {
+        self.min = Point::new(self.min.x.min(point.x), self.min.y.min(point.y));
+        self.max = Point::new(self.max.x.max(point.x), self.max.y.max(point.y));
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        (self.min.x..=self.max.x).contains(&point.x) && (self.min.y..=self.max.y).contains(&point.y)
+    }
+
+    pub fn width(&self) -> i32 {
+        self.max.x - self.min.x + 1
+    }
+
+    pub fn height(&self) -> i32 {
+        self.max.y - self.min.y + 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn point_arithmetic() {
+        let point = Point::new(3, -2);
+
+        assert_eq!(point + Point::new(1, 1), Point::new(4, -1));
+        assert_eq!(point - Point::new(1, 1), Point::new(2, -3));
+        assert_eq!(-point, Point::new(-3, 2));
+    }
+
+    #[test]
+    fn distances_order_irrelevant() {
+        let first = Point::new(1, 5);
+        let second = Point::new(4, 1);
+
+        assert_eq!(first.manhattan(second), 7);
+        assert_eq!(second.manhattan(first), 7);
+        assert_eq!(first.chebyshev(second), 4);
+        assert_eq!(second.chebyshev(first), 4);
+    }
+
+    #[test]
+    fn step_toward_moves_diagonally_when_both_coordinates_differ() {
+        let point = Point::ORIGIN;
+
+        assert_eq!(point.step_toward(Point::new(2, -1)), Point::new(1, -1));
+        assert_eq!(point.step_toward(Point::new(0, 5)), Point::new(0, 1));
+        assert_eq!(point.step_toward(point), point);
+    }
+
+    #[test]
+    fn chebyshev_horizontal_distance_order_irrelevant() {
+        let first = Point::new(1, 0);
+        let second = Point::new(3, 0);
+
+        assert_eq!(first.chebyshev(second), second.chebyshev(first));
+        assert_eq!(first.chebyshev(second), 2);
+    }
+
+    #[test]
+    fn chebyshev_vertical_distance_order_irrelevant() {
+        let first = Point::new(0, 1);
+        let second = Point::new(0, 3);
+
+        assert_eq!(first.chebyshev(second), second.chebyshev(first));
+        assert_eq!(first.chebyshev(second), 2);
+    }
+
+    #[test]
+    fn step_toward_when_diagonal_moves_both_coordinates() {
+        let point = Point::new(0, 0);
+
+        assert_eq!(point.step_toward(Point::new(1, 1)), Point::new(1, 1));
+    }
+
+    #[test]
+    fn step_toward_when_not_diagonal_moves_one_coordinate() {
+        let point = Point::new(1, 0);
+
+        assert_eq!(point.step_toward(Point::new(1, 1)), Point::new(1, 1));
+        assert_eq!(point.step_toward(Point::new(3, 0)), Point::new(2, 0));
+    }
+
+    #[test]
+    fn rotate_right_follows_turn_right() {
+        for direction in Direction::ALL {
+            assert_eq!(
+                direction.offset().rotate_right(),
+                direction.turn_right().offset()
+            );
+            assert_eq!(
+                direction.offset().rotate_left(),
+                direction.turn_left().offset()
+            );
+        }
+    }
+
+    #[test]
+    fn four_turns_come_back() {
+        let mut direction = Direction::UpLeft;
+        for _ in 0..4 {
+            direction = direction.turn_right();
+        }
+
+        assert_eq!(direction, Direction::UpLeft);
+        assert_eq!(Direction::Left.opposite(), Direction::Right);
+    }
+
+    #[test]
+    fn neighbours_are_at_distance_one() {
+        let point = Point::new(2, 2);
+
+        assert_eq!(point.neighbours4().count(), 4);
+        assert!(point.neighbours4().all(|other| point.manhattan(other) == 1));
+        assert_eq!(point.neighbours8().count(), 8);
+        assert!(point.neighbours8().all(|other| point.chebyshev(other) == 1));
+    }
+
+    #[test]
+    fn from_letter_reads_cardinal_directions() {
+        assert_eq!(Direction::from_letter('U'), Some(Direction::Up));
+        assert_eq!(Direction::from_letter('R'), Some(Direction::Right));
+        assert_eq!(Direction::from_letter('X'), None);
+        assert!(Direction::DownLeft.is_diagonal());
+    }
+
+    #[test]
+    fn bounding_box_holds_every_point() {
+        let points = [Point::new(1, 4), Point::new(-2, 0), Point::new(3, 2)];
+
+        let bounds = BoundingBox::from_points(points).unwrap();
+
+        assert_eq!(bounds.min(), Point::new(-2, 0));
+        assert_eq!(bounds.max(), Point::new(3, 4));
+        assert_eq!((bounds.width(), bounds.height()), (6, 5));
+        assert!(points.iter().all(|&point| bounds.contains(point)));
+        assert!(!bounds.contains(Point::new(4, 0)));
+    }
+
+    #[test]
+    fn bounding_box_of_nothing_is_none() {
+        assert_eq!(BoundingBox::from_points([]), None);
+    }
+}
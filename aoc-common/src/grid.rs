@@ -0,0 +1,393 @@
+use std::collections::HashMap;
+use std::ops::{Index, IndexMut};
+
+use crate::geometry::{BoundingBox, Direction, Point};
+use crate::parse::ParseError;
+
+/// A dense rectangle of cells indexed by [`Point`], with `(0, 0)` at the top left.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Reads one row per line, turning each character into a cell with `cell`.
+    ///
+    /// Fails on a character `cell` rejects and on a row whose length differs from the first.
+    pub fn parse<S: AsRef<str>>(
+        lines: impl IntoIterator<Item = S>,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+        for (index, line) in lines.into_iter().enumerate() {
+            let line = line.as_ref();
+            let row_start = cells.len();
+            for (column, character) in line.char_indices() {
+                let value = cell(character).ok_or_else(|| {
+                    let span = column..column + character.len_utf8();
+                    ParseError::unexpected(span.clone(), "a grid cell", &line[span])
+                        .offset_lines(index)
+                })?;
+                cells.push(value);
+            }
+
+            let row_width = cells.len() - row_start;
+            let width = *width.get_or_insert(row_width);
+            if row_width != width {
+                return Err(ParseError::unexpected(
+                    0..line.len(),
+                    format!("a row of {width} cells"),
+                    line,
+                )
+                .offset_lines(index));
+            }
+            height += 1;
+        }
+
+        Ok(Grid {
+            width: width.unwrap_or_default(),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        self.offset(point).is_some()
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.offset(point).map(|offset| &self.cells[offset])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.offset(point).map(|offset| &mut self.cells[offset])
+    }
+
+    /// Every point of the grid, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |offset| point_at(offset, width))
+    }
+
+    /// Every cell with its point, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(&self.cells)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` rejects a size of 0, and a grid without columns has no cells anyway.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |column| self.cells.iter().skip(column).step_by(self.width))
+    }
+
+    /// The cells up, right, down and left of `point` that lie inside the grid.
+    pub fn neighbours4(&self, point: Point) -> impl Iterator<Item = (Point, &T)> {
+        point
+            .neighbours4()
+            .filter_map(move |neighbour| Some((neighbour, self.get(neighbour)?)))
+    }
+
+    /// The cells around `point`, diagonals included, that lie inside the grid.
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = (Point, &T)> {
+        point
+            .neighbours8()
+            .filter_map(move |neighbour| Some((neighbour, self.get(neighbour)?)))
+    }
+
+    /// Walks from `point` in `direction` until leaving the grid, `point` excluded.
+    pub fn ray(&self, point: Point, direction: Direction) -> impl Iterator<Item = (Point, &T)> {
+        let mut current = point;
+        std::iter::from_fn(move || {
+            current = current.neighbour(direction);
+            Some((current, self.get(current)?))
+        })
+    }
+
+    /// `None` for an empty grid.
+    pub fn bounds(&self) -> Option<BoundingBox> {
+        BoundingBox::from_points([self.points().next()?, self.points().last()?])
+    }
+
+    /// Draws the grid as one line per row, `cell` giving the character for each cell.
+    pub fn render(&self, mut cell: impl FnMut(&T) -> char) -> String {
+        let mut text = String::with_capacity((self.width + 1) * self.height);
+        for row in self.rows().take(self.height) {
+            text.extend(row.iter().map(&mut cell));
+            text.push('\n');
+        }
+        text
+    }
+
+    fn offset(&self, point: Point) -> Option<usize> {
+        let x = usize::try_from(point.x()).ok()?;
+        let y = usize::try_from(point.y()).ok()?;
+        (x < self.width && y < self.height).then_some(y * self.width + x)
+    }
+}
+
+fn point_at(offset: usize, width: usize) -> Point {
+    let coordinate = |value: usize| i32::try_from(value).expect("grid fits in i32 coordinates");
+    Point::new(coordinate(offset % width), coordinate(offset / width))
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point)
+            .unwrap_or_else(|| panic!("{point:?} is outside the grid"))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{point:?} is outside the grid"))
+    }
+}
+
+/// A grid without fixed bounds that only stores the cells that were set.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point, T>,
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        SparseGrid {
+            cells: HashMap::new(),
+        }
+    }
+
+    /// Sets the cell at `point`, returning the value it held before.
+    pub fn insert(&mut self, point: Point, value: T) -> Option<T> {
+        self.cells.insert(point, value)
+    }
+
+    pub fn remove(&mut self, point: Point) -> Option<T> {
+        self.cells.remove(&point)
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        self.cells.contains_key(&point)
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.cells.get(&point)
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.cells.get_mut(&point)
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// The set cells in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells.iter().map(|(point, value)| (*point, value))
+    }
+
+    /// The set cells up, right, down and left of `point`.
+    pub fn neighbours4(&self, point: Point) -> impl Iterator<Item = (Point, &T)> {
+        point
+            .neighbours4()
+            .filter_map(move |neighbour| Some((neighbour, self.get(neighbour)?)))
+    }
+
+    /// The set cells around `point`, diagonals included.
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = (Point, &T)> {
+        point
+            .neighbours8()
+            .filter_map(move |neighbour| Some((neighbour, self.get(neighbour)?)))
+    }
+
+    /// The smallest box holding every set cell, `None` when there are none.
+    pub fn bounds(&self) -> Option<BoundingBox> {
+        BoundingBox::from_points(self.cells.keys().copied())
+    }
+
+    /// Draws the bounding box of the set cells, using `empty` for the cells that are not set.
+    pub fn render(&self, empty: char, mut cell: impl FnMut(&T) -> char) -> String {
+        let Some(bounds) = self.bounds() else {
+            return String::new();
+        };
+        let mut text = String::new();
+        for y in bounds.min().y()..=bounds.max().y() {
+            for x in bounds.min().x()..=bounds.max().x() {
+                text.push(self.get(Point::new(x, y)).map_or(empty, &mut cell));
+            }
+            text.push('\n');
+        }
+        text
+    }
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> FromIterator<(Point, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Point, T)>>(cells: I) -> Self {
+        SparseGrid {
+            cells: cells.into_iter().collect(),
+        }
+    }
+}
+
+impl<T> From<Grid<T>> for SparseGrid<T> {
+    fn from(grid: Grid<T>) -> Self {
+        let width = grid.width;
+        grid.cells
+            .into_iter()
+            .enumerate()
+            .map(|(offset, value)| (point_at(offset, width), value))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::ParseErrorKind;
+
+    const TREES: [&str; 3] = ["303", "255", "653"];
+
+    fn trees() -> Grid<u32> {
+        Grid::parse(TREES, |character| character.to_digit(10)).unwrap()
+    }
+
+    #[test]
+    fn parse_reads_rows_of_cells() {
+        let grid = trees();
+
+        assert_eq!((grid.width(), grid.height()), (3, 3));
+        assert_eq!(grid[Point::new(0, 1)], 2);
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(grid.get(Point::new(0, -1)), None);
+    }
+
+    #[test]
+    fn parse_when_cell_rejected_reports_position() {
+        let result = Grid::parse(["12", "3x"], |character| character.to_digit(10)).unwrap_err();
+
+        assert_eq!(result.kind(), ParseErrorKind::UnexpectedToken);
+        assert_eq!(result.line(), 2);
+        assert_eq!(result.span(), 1..2);
+        assert_eq!(result.found(), "x");
+    }
+
+    #[test]
+    fn parse_when_rows_differ_in_length_returns_error() {
+        let result = Grid::parse(["123", "45"], |character| character.to_digit(10)).unwrap_err();
+
+        assert_eq!(result.line(), 2);
+        assert_eq!(result.expected(), "a row of 3 cells");
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = trees();
+
+        let rows: Vec<&[u32]> = grid.rows().collect();
+        let columns: Vec<Vec<u32>> = grid
+            .columns()
+            .map(|column| column.copied().collect())
+            .collect();
+
+        assert_eq!(rows, [[3, 0, 3], [2, 5, 5], [6, 5, 3]]);
+        assert_eq!(columns, [[3, 2, 6], [0, 5, 5], [3, 5, 3]]);
+    }
+
+    #[test]
+    fn neighbours_stay_inside_the_grid() {
+        let grid = trees();
+
+        assert_eq!(grid.neighbours4(Point::ORIGIN).count(), 2);
+        assert_eq!(grid.neighbours8(Point::ORIGIN).count(), 3);
+        assert_eq!(grid.neighbours8(Point::new(1, 1)).count(), 8);
+    }
+
+    #[test]
+    fn ray_walks_to_the_edge() {
+        let grid = trees();
+
+        let cells: Vec<u32> = grid
+            .ray(Point::new(0, 1), Direction::Right)
+            .map(|(_, height)| *height)
+            .collect();
+
+        assert_eq!(cells, [5, 5]);
+    }
+
+    #[test]
+    fn index_mut_updates_cell() {
+        let mut grid = Grid::new(2, 2, '.');
+
+        grid[Point::new(1, 0)] = '#';
+
+        assert_eq!(grid.render(|cell| *cell), ".#\n..\n");
+        assert_eq!(grid.bounds().unwrap().max(), Point::new(1, 1));
+    }
+
+    #[test]
+    fn render_round_trips_parse() {
+        let grid = trees();
+
+        let text = grid.render(|height| char::from_digit(*height, 10).unwrap());
+
+        assert_eq!(text, "303\n255\n653\n");
+    }
+
+    #[test]
+    fn sparse_grid_renders_its_bounding_box() {
+        let grid: SparseGrid<char> = [(Point::new(-1, 0), 's'), (Point::new(1, 1), '#')]
+            .into_iter()
+            .collect();
+
+        assert_eq!(grid.render('.', |cell| *cell), "s..\n..#\n");
+        assert_eq!(grid.neighbours8(Point::ORIGIN).count(), 2);
+        assert_eq!(SparseGrid::<char>::new().render('.', |cell| *cell), "");
+    }
+
+    #[test]
+    fn sparse_grid_from_grid_keeps_every_cell() {
+        let sparse = SparseGrid::from(trees());
+
+        assert_eq!(sparse.len(), 9);
+        assert_eq!(sparse.get(Point::new(2, 1)), Some(&5));
+    }
+}
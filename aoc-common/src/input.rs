@@ -0,0 +1,134 @@
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::path::{Path, PathBuf};
+
+use clap::Parser;
+
+use crate::file_util::{read_input, read_stdin, InputError};
+use crate::lines::LineReader;
+
+/// Where a puzzle input is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    pub fn load(&self) -> Result<String, InputError> {
+        match self {
+            InputSource::File(path) => read_input(path),
+            InputSource::Stdin => read_stdin(),
+        }
+    }
+
+    /// Opens the input for reading line by line instead of all at once.
+    pub fn lines(&self) -> Result<LineReader<Box<dyn BufRead>>, InputError> {
+        let reader: Box<dyn BufRead> = match self {
+            InputSource::File(path) => {
+                let file = File::open(path).map_err(|error| InputError::new(path, None, error))?;
+                Box::new(BufReader::new(file))
+            }
+            InputSource::Stdin => Box::new(io::stdin().lock()),
+        };
+        let path = match self {
+            InputSource::File(path) => path.as_path(),
+            InputSource::Stdin => Path::new("-"),
+        };
+        Ok(LineReader::new(reader, path))
+    }
+}
+
+/// Command-line options shared by every day to choose its puzzle input.
+#[derive(Parser, Debug, Default)]
+pub struct InputArgs {
+    /// Read the puzzle input from this file, or from stdin when `-`
+    #[arg(long, value_name = "PATH", conflicts_with = "test")]
+    pub input: Option<PathBuf>,
+
+    /// Use input_test.txt instead of input.txt
+    #[arg(long)]
+    pub test: bool,
+}
+
+impl InputArgs {
+    /// Resolves the input against a day's directory, which holds `input.txt` and `input_test.txt`.
+    pub fn source_in(&self, dir: &Path) -> InputSource {
+        match &self.input {
+            Some(path) if path.as_os_str() == "-" => InputSource::Stdin,
+            Some(path) => InputSource::File(path.clone()),
+            None if self.test => InputSource::File(dir.join("input_test.txt")),
+            None => InputSource::File(dir.join("input.txt")),
+        }
+    }
+
+    /// Loads the input relative to the current directory.
+    pub fn load(&self) -> Result<String, InputError> {
+        self.source_in(Path::new(".")).load()
+    }
+
+    /// Opens the input relative to the current directory for reading line by line.
+    pub fn lines(&self) -> Result<LineReader<Box<dyn BufRead>>, InputError> {
+        self.source_in(Path::new(".")).lines()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn source_in_defaults_to_input_txt() {
+        let args = InputArgs::parse_from(["day"]);
+
+        assert_eq!(
+            args.source_in(Path::new("day01")),
+            InputSource::File(PathBuf::from("day01/input.txt"))
+        );
+    }
+
+    #[test]
+    fn source_in_when_test_uses_input_test_txt() {
+        let args = InputArgs::parse_from(["day", "--test"]);
+
+        assert_eq!(
+            args.source_in(Path::new(".")),
+            InputSource::File(PathBuf::from("./input_test.txt"))
+        );
+    }
+
+    #[test]
+    fn source_in_when_dash_reads_stdin() {
+        let args = InputArgs::parse_from(["day", "--input", "-"]);
+
+        assert_eq!(args.source_in(Path::new(".")), InputSource::Stdin);
+    }
+
+    #[test]
+    fn source_in_when_path_given_ignores_dir() {
+        let args = InputArgs::parse_from(["day", "--input", "other.txt"]);
+
+        assert_eq!(
+            args.source_in(Path::new("day01")),
+            InputSource::File(PathBuf::from("other.txt"))
+        );
+    }
+
+    #[test]
+    fn lines_when_missing_reports_path() {
+        let path = PathBuf::from("aoc-common-missing-input.txt");
+
+        let Err(error) = InputSource::File(path.clone()).lines() else {
+            panic!("expected a missing file error");
+        };
+
+        assert_eq!(error.path(), path);
+    }
+
+    #[test]
+    fn parse_when_input_and_test_returns_error() {
+        let result = InputArgs::try_parse_from(["day", "--input", "a.txt", "--test"]);
+
+        assert!(result.is_err());
+    }
+}
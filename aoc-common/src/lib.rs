@@ -0,0 +1,29 @@
+mod checked;
+mod error;
+mod file_util;
+mod geometry;
+mod grid;
+mod input;
+mod lines;
+mod parse;
+mod records;
+mod report;
+mod search;
+mod solution;
+mod vector_util;
+
+pub use checked::{checked_sum, CheckedAdd, Overflow};
+pub use error::{exit_code, Error};
+pub use file_util::{read_all_lines, read_input, read_stdin, InputError};
+pub use geometry::{BoundingBox, Direction, Point};
+pub use grid::{Grid, SparseGrid};
+pub use input::{InputArgs, InputSource};
+pub use lines::LineReader;
+pub use parse::{parse_lines, ParseError, ParseErrorKind, Token, Tokens};
+pub use records::{records, Record, Records};
+pub use report::{DayArgs, Format, Report};
+pub use search::{astar, bfs, connected_components, dijkstra, flood_fill, Path};
+pub use solution::{
+    solve_part1, solve_part2, time_part1, time_part2, PhaseTimings, Solution, Unsolved,
+};
+pub use vector_util::{top_k, top_k_indices, Order, TopK};
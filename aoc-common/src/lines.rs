@@ -0,0 +1,223 @@
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
+
+use crate::{Error, InputError, ParseError};
+
+/// Reads an input one line at a time, so it never has to fit in memory as a whole.
+///
+/// Lines come without their line ending, either borrowed from a single reused buffer through
+/// [`LineReader::next_line`] or as owned strings through the `Iterator` implementation. Reading
+/// stops at the first error, which carries the number of the line that could not be read.
+pub struct LineReader<R> {
+    reader: R,
+    path: PathBuf,
+    buffer: String,
+    line: usize,
+    failed: bool,
+}
+
+impl LineReader<BufReader<File>> {
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, InputError> {
+        let path = path.as_ref();
+        let file = File::open(path).map_err(|error| InputError::new(path, None, error))?;
+        Ok(LineReader::new(BufReader::new(file), path))
+    }
+}
+
+impl<R: BufRead> LineReader<R> {
+    /// Wraps `reader`, reporting errors against `path`.
+    pub fn new(reader: R, path: impl Into<PathBuf>) -> Self {
+        LineReader {
+            reader,
+            path: path.into(),
+            buffer: String::new(),
+            line: 0,
+            failed: false,
+        }
+    }
+
+    /// 1-based number of the line returned last, 0 before the first one.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Reads the next line into the shared buffer and borrows it.
+    pub fn next_line(&mut self) -> Option<Result<&str, InputError>> {
+        if self.failed {
+            return None;
+        }
+
+        self.buffer.clear();
+        match self.reader.read_line(&mut self.buffer) {
+            Ok(0) => None,
+            Ok(_) => {
+                self.line += 1;
+                let line = self.buffer.strip_suffix('\n').unwrap_or(&self.buffer);
+                Some(Ok(line.strip_suffix('\r').unwrap_or(line)))
+            }
+            Err(error) => {
+                self.line += 1;
+                self.failed = true;
+                Some(Err(InputError::new(&self.path, Some(self.line), error)))
+            }
+        }
+    }
+
+    /// Hands every line to `f`, borrowed from the shared buffer, so only one line is held at a
+    /// time.
+    ///
+    /// `f` sees a single line, so its parse errors are moved to the line that was read.
+    pub fn for_each_line<E>(mut self, mut f: impl FnMut(&str) -> Result<(), E>) -> Result<(), Error>
+    where
+        Error: From<E>,
+    {
+        while let Some(line) = self.next_line() {
+            if let Err(error) = f(line?) {
+                return Err(Error::from(error).offset_lines(self.line - 1));
+            }
+        }
+        Ok(())
+    }
+
+    /// Hands the lines to `parse`, which sees the input end early if a line cannot be read.
+    ///
+    /// A read error takes precedence over the parse result, as the parser only saw part of
+    /// the input.
+    pub fn parse<T>(
+        mut self,
+        parse: impl FnOnce(&mut dyn Iterator<Item = String>) -> Result<T, ParseError>,
+    ) -> Result<T, Error> {
+        let mut read_error = None;
+        let mut lines = std::iter::from_fn(|| match self.next()? {
+            Ok(line) => Some(line),
+            Err(error) => {
+                read_error = Some(error);
+                None
+            }
+        });
+
+        let result = parse(&mut lines);
+        if let Some(error) = read_error {
+            return Err(error.into());
+        }
+        Ok(result?)
+    }
+}
+
+impl<R: BufRead> Iterator for LineReader<R> {
+    type Item = Result<String, InputError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_line().map(|line| line.map(String::from))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Token;
+
+    fn reader(text: &'static [u8]) -> LineReader<&'static [u8]> {
+        LineReader::new(text, "input.txt")
+    }
+
+    #[test]
+    fn next_line_strips_line_endings() {
+        let mut lines = reader(b"a\r\nb\n\nc");
+
+        assert_eq!(lines.next_line().unwrap().unwrap(), "a");
+        assert_eq!(lines.next_line().unwrap().unwrap(), "b");
+        assert_eq!(lines.next_line().unwrap().unwrap(), "");
+        assert_eq!(lines.next_line().unwrap().unwrap(), "c");
+        assert!(lines.next_line().is_none());
+        assert_eq!(lines.line(), 4);
+    }
+
+    #[test]
+    fn iterator_matches_str_lines() {
+        let text = "1000\n2000\n\n3000\n";
+
+        let lines: Vec<String> = reader(text.as_bytes()).map(Result::unwrap).collect();
+
+        assert_eq!(lines, text.lines().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn iterator_when_invalid_utf8_stops_with_line() {
+        let mut lines = reader(b"a\n\xff\nc\n");
+
+        assert!(lines.next().unwrap().is_ok());
+        let error = lines.next().unwrap().unwrap_err();
+        assert_eq!(error.line(), Some(2));
+        assert!(lines.next().is_none());
+    }
+
+    #[test]
+    fn parse_when_read_fails_reports_read_error() {
+        let result = reader(b"1\n\xff\n").parse(|lines| Ok(lines.count()));
+
+        assert!(matches!(result, Err(Error::Input(error)) if error.line() == Some(2)));
+    }
+
+    /// Yields `count` lines of `1000` without ever holding more than one of them.
+    struct Generated {
+        count: usize,
+        offset: usize,
+    }
+
+    impl std::io::Read for Generated {
+        fn read(&mut self, buffer: &mut [u8]) -> std::io::Result<usize> {
+            const LINE: &[u8] = b"1000\n";
+            let mut written = 0;
+            while written < buffer.len() && self.count > 0 {
+                buffer[written] = LINE[self.offset];
+                written += 1;
+                self.offset += 1;
+                if self.offset == LINE.len() {
+                    self.offset = 0;
+                    self.count -= 1;
+                }
+            }
+            Ok(written)
+        }
+    }
+
+    #[test]
+    fn next_line_reads_long_input_through_one_small_buffer() {
+        let count = 1_000_000;
+        let mut lines = LineReader::new(
+            std::io::BufReader::new(Generated { count, offset: 0 }),
+            "generated",
+        );
+        let mut total = 0u64;
+
+        while let Some(line) = lines.next_line() {
+            total += line.unwrap().parse::<u64>().unwrap();
+        }
+
+        assert_eq!(total, 1000 * count as u64);
+        assert_eq!(lines.line(), count);
+        assert!(lines.buffer.capacity() < 64);
+    }
+
+    #[test]
+    fn for_each_line_reports_errors_at_their_line() {
+        let mut seen = Vec::new();
+
+        let result = reader(b"1\n2\nx\n4\n").for_each_line(|line| {
+            seen.push(String::from(line));
+            Token::new(line).parse_number::<i32>("a number").map(drop)
+        });
+
+        assert!(matches!(result, Err(Error::Parse(error)) if error.line() == 3));
+        assert_eq!(seen, ["1", "2", "x"]);
+    }
+
+    #[test]
+    fn parse_passes_every_line() {
+        let result = reader(b"1\n2\n3").parse(|lines| Ok(lines.collect::<Vec<_>>()));
+
+        assert_eq!(result.unwrap(), ["1", "2", "3"]);
+    }
+}
@@ -0,0 +1,283 @@
+use std::fmt;
+use std::ops::Range;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// A token was present but is not one of the accepted values.
+    UnexpectedToken,
+    /// The line ended before a required token was found.
+    MissingToken,
+    /// A token that should hold a number could not be parsed as one.
+    InvalidNumber,
+}
+
+/// Failure to turn a piece of puzzle input into a model.
+///
+/// `line` is 1-based and `span` holds 0-based byte offsets within that line. Parsers that only
+/// see a single line report line 1; callers that know where the line sits in the whole input
+/// move the error there with [`ParseError::offset_lines`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    kind: ParseErrorKind,
+    line: usize,
+    span: Range<usize>,
+    expected: String,
+    found: String,
+}
+
+impl ParseError {
+    pub fn new(
+        kind: ParseErrorKind,
+        span: Range<usize>,
+        expected: impl Into<String>,
+        found: impl Into<String>,
+    ) -> Self {
+        ParseError {
+            kind,
+            line: 1,
+            span,
+            expected: expected.into(),
+            found: found.into(),
+        }
+    }
+
+    pub fn unexpected(span: Range<usize>, expected: impl Into<String>, found: &str) -> Self {
+        Self::new(ParseErrorKind::UnexpectedToken, span, expected, found)
+    }
+
+    pub fn missing(column: usize, expected: impl Into<String>) -> Self {
+        Self::new(
+            ParseErrorKind::MissingToken,
+            column..column,
+            expected,
+            "end of line",
+        )
+    }
+
+    pub fn offset_lines(mut self, lines: usize) -> Self {
+        self.line += lines;
+        self
+    }
+
+    pub fn offset_columns(mut self, columns: usize) -> Self {
+        self.span = self.span.start + columns..self.span.end + columns;
+        self
+    }
+
+    pub fn kind(&self) -> ParseErrorKind {
+        self.kind
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+
+    pub fn expected(&self) -> &str {
+        &self.expected
+    }
+
+    pub fn found(&self) -> &str {
+        &self.found
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let start = self.span.start + 1;
+        if self.span.len() > 1 {
+            write!(f, "line {}, columns {}-{}", self.line, start, self.span.end)?;
+        } else {
+            write!(f, "line {}, column {}", self.line, start)?;
+        }
+        write!(f, ": expected {}, found {:?}", self.expected, self.found)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// A piece of an input line together with the columns it was taken from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Token<'a> {
+    pub text: &'a str,
+    pub span: Range<usize>,
+}
+
+impl<'a> Token<'a> {
+    /// A token covering all of `text`.
+    pub fn new(text: &'a str) -> Self {
+        Token {
+            text,
+            span: 0..text.len(),
+        }
+    }
+
+    pub fn parse_number<T: FromStr>(&self, expected: &str) -> Result<T, ParseError> {
+        self.text.parse().map_err(|_| {
+            ParseError::new(
+                ParseErrorKind::InvalidNumber,
+                self.span.clone(),
+                expected,
+                self.text,
+            )
+        })
+    }
+
+    pub fn unexpected(&self, expected: impl Into<String>) -> ParseError {
+        ParseError::unexpected(self.span.clone(), expected, self.text)
+    }
+}
+
+/// Splits a line on a separator while keeping track of the column of every token.
+pub struct Tokens<'a> {
+    rest: Option<&'a str>,
+    offset: usize,
+    separator: char,
+}
+
+impl<'a> Tokens<'a> {
+    pub fn new(line: &'a str, separator: char) -> Self {
+        Tokens {
+            rest: Some(line),
+            offset: 0,
+            separator,
+        }
+    }
+
+    /// Returns the next token, or a [`ParseErrorKind::MissingToken`] error naming what was expected.
+    pub fn expect(&mut self, expected: &str) -> Result<Token<'a>, ParseError> {
+        let column = self.offset;
+        self.next()
+            .ok_or_else(|| ParseError::missing(column, expected))
+    }
+
+    /// Fails on the first token left on the line, if any.
+    pub fn finish(mut self, expected: &str) -> Result<(), ParseError> {
+        match self.next() {
+            Some(token) => Err(token.unexpected(expected)),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<'a> Iterator for Tokens<'a> {
+    type Item = Token<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = self.rest?;
+        let start = self.offset;
+        let text = match rest.split_once(self.separator) {
+            Some((text, remainder)) => {
+                self.rest = Some(remainder);
+                self.offset += text.len() + self.separator.len_utf8();
+                text
+            }
+            None => {
+                self.rest = None;
+                self.offset += rest.len();
+                rest
+            }
+        };
+
+        Some(Token {
+            text,
+            span: start..start + text.len(),
+        })
+    }
+}
+
+/// Parses every line of the input, reporting errors at the line they occurred on.
+pub fn parse_lines<T>(
+    input: &str,
+    mut parse: impl FnMut(&str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| parse(line).map_err(|error| error.offset_lines(index)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tokens_tracks_spans() {
+        let tokens: Vec<Token> = Tokens::new("move 12 from", ' ').collect();
+
+        assert_eq!(tokens[0].span, 0..4);
+        assert_eq!(tokens[1].text, "12");
+        assert_eq!(tokens[1].span, 5..7);
+        assert_eq!(tokens[2].span, 8..12);
+    }
+
+    #[test]
+    fn tokens_keeps_empty_tokens() {
+        let tokens: Vec<&str> = Tokens::new("a,,b", ',').map(|token| token.text).collect();
+
+        assert_eq!(tokens, vec!["a", "", "b"]);
+    }
+
+    #[test]
+    fn expect_when_line_ended_returns_missing_at_end() {
+        let mut tokens = Tokens::new("A", ' ');
+        tokens.next();
+
+        let result = tokens.expect("player choice").unwrap_err();
+
+        assert_eq!(result.kind(), ParseErrorKind::MissingToken);
+        assert_eq!(result.span(), 1..1);
+        assert_eq!(result.expected(), "player choice");
+    }
+
+    #[test]
+    fn finish_when_tokens_left_returns_unexpected() {
+        let mut tokens = Tokens::new("A X Y", ' ');
+        tokens.next();
+        tokens.next();
+
+        let result = tokens.finish("end of line").unwrap_err();
+
+        assert_eq!(result.kind(), ParseErrorKind::UnexpectedToken);
+        assert_eq!(result.span(), 4..5);
+        assert_eq!(result.found(), "Y");
+    }
+
+    #[test]
+    fn parse_number_when_not_a_number_returns_invalid_number() {
+        let token = Token {
+            text: "1x",
+            span: 3..5,
+        };
+
+        let result = token.parse_number::<i32>("a size").unwrap_err();
+
+        assert_eq!(result.kind(), ParseErrorKind::InvalidNumber);
+        assert_eq!(result.span(), 3..5);
+        assert_eq!(result.found(), "1x");
+    }
+
+    #[test]
+    fn parse_lines_reports_line_number() {
+        let result = parse_lines("1\n2\nx", |line| {
+            Token::new(line).parse_number::<i32>("a number")
+        });
+
+        assert_eq!(result.unwrap_err().line(), 3);
+    }
+
+    #[test]
+    fn display_shows_position_expected_and_found() {
+        let error = ParseError::unexpected(2..4, "one of A, B or C", "XY").offset_lines(6);
+
+        assert_eq!(
+            error.to_string(),
+            "line 7, columns 3-4: expected one of A, B or C, found \"XY\""
+        );
+    }
+}
@@ -0,0 +1,143 @@
+use crate::ParseError;
+
+/// A group of consecutive non-blank lines.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    line: usize,
+    lines: Vec<String>,
+}
+
+impl Record {
+    /// 1-based number of the record's first line in the whole input.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn lines(&self) -> &[String] {
+        &self.lines
+    }
+
+    pub fn into_lines(self) -> Vec<String> {
+        self.lines
+    }
+
+    /// Parses the record's lines, moving errors from record-relative lines to input lines.
+    pub fn parse<T>(
+        &self,
+        parse: impl FnOnce(&[String]) -> Result<T, ParseError>,
+    ) -> Result<T, ParseError> {
+        parse(&self.lines).map_err(|error| error.offset_lines(self.line - 1))
+    }
+}
+
+/// Groups lines into records separated by one or more blank lines, reading only as far as the
+/// record being returned.
+///
+/// Lines holding only whitespace, including a `\r` left over from a CRLF ending, count as blank.
+/// A trailing `\r` is removed from the lines kept in a record.
+pub fn records<S: AsRef<str>, I: IntoIterator<Item = S>>(lines: I) -> Records<I::IntoIter> {
+    Records {
+        lines: lines.into_iter(),
+        line: 0,
+    }
+}
+
+/// Iterator returned by [`records`].
+pub struct Records<I> {
+    lines: I,
+    /// Number of lines consumed so far.
+    line: usize,
+}
+
+impl<I, S> Iterator for Records<I>
+where
+    I: Iterator<Item = S>,
+    S: AsRef<str>,
+{
+    type Item = Record;
+
+    fn next(&mut self) -> Option<Record> {
+        let mut start = self.line + 1;
+        let mut lines = Vec::new();
+        for line in self.lines.by_ref() {
+            self.line += 1;
+            let line = line.as_ref();
+            if !line.trim().is_empty() {
+                lines.push(String::from(line.strip_suffix('\r').unwrap_or(line)));
+            } else if lines.is_empty() {
+                start = self.line + 1;
+            } else {
+                break;
+            }
+        }
+
+        if lines.is_empty() {
+            return None;
+        }
+        Some(Record { line: start, lines })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn texts(record: &Record) -> Vec<&str> {
+        record.lines().iter().map(String::as_str).collect()
+    }
+
+    #[test]
+    fn records_split_on_blank_lines() {
+        let result: Vec<Record> = records(["a", "b", "", "c"]).collect();
+
+        assert_eq!(result.len(), 2);
+        assert_eq!(texts(&result[0]), ["a", "b"]);
+        assert_eq!(texts(&result[1]), ["c"]);
+    }
+
+    #[test]
+    fn records_skip_repeated_leading_and_trailing_blank_lines() {
+        let result: Vec<Record> = records(["", "a", "", "", "b", "", ""]).collect();
+
+        let lines: Vec<(usize, Vec<&str>)> = result
+            .iter()
+            .map(|record| (record.line(), texts(record)))
+            .collect();
+        assert_eq!(lines, [(2, vec!["a"]), (5, vec!["b"])]);
+    }
+
+    #[test]
+    fn records_handle_crlf_endings() {
+        let result: Vec<Record> = records("a\r\nb\r\n\r\nc\r\n".split('\n')).collect();
+
+        assert_eq!(texts(&result[0]), ["a", "b"]);
+        assert_eq!(result[1].line(), 4);
+        assert_eq!(texts(&result[1]), ["c"]);
+    }
+
+    #[test]
+    fn records_are_read_lazily() {
+        let mut consumed = 0;
+        let lines = ["a", "", "b", "", "c"]
+            .into_iter()
+            .inspect(|_| consumed += 1);
+
+        let first = records(lines).next().unwrap();
+
+        assert_eq!(texts(&first), ["a"]);
+        assert_eq!(consumed, 2);
+    }
+
+    #[test]
+    fn parse_reports_errors_at_input_line() {
+        let record = records(["1", "", "2", "x"]).nth(1).unwrap();
+
+        let result = record
+            .parse(|lines| {
+                Err::<(), _>(ParseError::missing(0, "a number").offset_lines(lines.len() - 1))
+            })
+            .unwrap_err();
+
+        assert_eq!(result.line(), 4);
+    }
+}
@@ -0,0 +1,195 @@
+use std::fmt::Write;
+
+use clap::{Parser, ValueEnum};
+use sha2::{Digest, Sha256};
+
+use crate::solution::measure;
+use crate::{Error, InputArgs, PhaseTimings, Solution};
+
+/// How a day binary prints its answers.
+#[derive(ValueEnum, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Format {
+    /// A sentence per answer, for people
+    #[default]
+    Text,
+    /// A JSON object per part on its own line, for scripts
+    Json,
+}
+
+/// Command-line options shared by every day binary.
+#[derive(Parser, Debug, Default)]
+pub struct DayArgs {
+    #[command(flatten)]
+    pub input: InputArgs,
+
+    /// How to print the answers
+    #[arg(long, value_enum, default_value_t)]
+    pub format: Format,
+}
+
+/// The answer to one part together with how it was obtained, for machine-readable output.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub timings: PhaseTimings,
+    /// Hex SHA-256 of the puzzle input, to tell which input an answer belongs to.
+    pub input_hash: String,
+}
+
+impl Report {
+    pub fn part1<S: Solution>(year: u16, day: u8, input: &str) -> Result<Report, Error> {
+        let (answer, timings) = measure::<S>(input, |parsed| Ok(S::part1(parsed)?.to_string()))?;
+        Ok(Report::new(year, day, 1, answer, timings, input))
+    }
+
+    pub fn part2<S: Solution>(year: u16, day: u8, input: &str) -> Result<Report, Error> {
+        let (answer, timings) = measure::<S>(input, |parsed| Ok(S::part2(parsed)?.to_string()))?;
+        Ok(Report::new(year, day, 2, answer, timings, input))
+    }
+
+    fn new(
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: String,
+        timings: PhaseTimings,
+        input: &str,
+    ) -> Report {
+        Report {
+            year,
+            day,
+            part,
+            answer,
+            timings,
+            input_hash: sha256_hex(input),
+        }
+    }
+
+    /// Single-line JSON object. The answer is always a string, as answers are not all numbers.
+    pub fn to_json(&self) -> String {
+        format!(
+            "{{\"year\":{},\"day\":{},\"part\":{},\"answer\":{},\
+             \"timing\":{{\"parse_ns\":{},\"solve_ns\":{}}},\"input_sha256\":\"{}\"}}",
+            self.year,
+            self.day,
+            self.part,
+            json_string(&self.answer),
+            self.timings.parse.as_nanos(),
+            self.timings.solve.as_nanos(),
+            self.input_hash,
+        )
+    }
+}
+
+fn sha256_hex(input: &str) -> String {
+    let digest = Sha256::digest(input.as_bytes());
+    let mut hex = String::with_capacity(digest.len() * 2);
+    for byte in digest {
+        let _ = write!(hex, "{byte:02x}");
+    }
+    hex
+}
+
+fn json_string(text: &str) -> String {
+    let mut quoted = String::with_capacity(text.len() + 2);
+    quoted.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c if c.is_control() => {
+                let _ = write!(quoted, "\\u{:04x}", c as u32);
+            }
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt::Display;
+    use std::time::Duration;
+
+    use super::*;
+
+    struct Echo;
+
+    impl Solution for Echo {
+        type Input = String;
+
+        fn parse(input: &str) -> Result<Self::Input, Error> {
+            Ok(String::from(input.trim()))
+        }
+
+        fn part1(input: &Self::Input) -> Result<impl Display, Error> {
+            Ok(input.clone())
+        }
+    }
+
+    #[test]
+    fn sha256_hex_matches_known_digest() {
+        assert_eq!(
+            sha256_hex("abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[test]
+    fn json_string_escapes_quotes_and_control_characters() {
+        assert_eq!(json_string("a\"b\\c\n\u{1}"), "\"a\\\"b\\\\c\\n\\u0001\"");
+    }
+
+    #[test]
+    fn to_json_writes_all_fields() {
+        let report = Report {
+            year: 2022,
+            day: 5,
+            part: 1,
+            answer: String::from("CMZ"),
+            timings: PhaseTimings {
+                parse: Duration::from_nanos(1500),
+                solve: Duration::from_nanos(20),
+            },
+            input_hash: String::from("ab12"),
+        };
+
+        assert_eq!(
+            report.to_json(),
+            "{\"year\":2022,\"day\":5,\"part\":1,\"answer\":\"CMZ\",\
+             \"timing\":{\"parse_ns\":1500,\"solve_ns\":20},\"input_sha256\":\"ab12\"}"
+        );
+    }
+
+    #[test]
+    fn part1_reports_answer_and_input_hash() {
+        let report = Report::part1::<Echo>(2022, 1, "abc").unwrap();
+
+        assert_eq!(report.part, 1);
+        assert_eq!(report.answer, "abc");
+        assert_eq!(report.input_hash, sha256_hex("abc"));
+    }
+
+    #[test]
+    fn part2_when_not_implemented_reports_unsolved() {
+        let report = Report::part2::<Echo>(2022, 1, "abc").unwrap();
+
+        assert_eq!(report.answer, "unsolved");
+    }
+
+    #[test]
+    fn day_args_defaults_to_text() {
+        assert_eq!(DayArgs::parse_from(["day"]).format, Format::Text);
+        assert_eq!(
+            DayArgs::parse_from(["day", "--test", "--format", "json"]).format,
+            Format::Json
+        );
+    }
+}
@@ -0,0 +1,318 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// A path found by a search, from the start node to the goal node, both included.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<N, C> {
+    nodes: Vec<N>,
+    cost: C,
+}
+
+impl<N, C: Copy> Path<N, C> {
+    pub fn nodes(&self) -> &[N] {
+        &self.nodes
+    }
+
+    pub fn cost(&self) -> C {
+        self.cost
+    }
+
+    pub fn start(&self) -> &N {
+        self.nodes.first().expect("a path holds at least its start")
+    }
+
+    pub fn end(&self) -> &N {
+        self.nodes.last().expect("a path holds at least its start")
+    }
+
+    /// The number of steps taken, one less than the number of nodes.
+    pub fn steps(&self) -> usize {
+        self.nodes.len() - 1
+    }
+
+    pub fn into_nodes(self) -> Vec<N> {
+        self.nodes
+    }
+}
+
+/// Breadth-first search, the cost being the number of steps.
+///
+/// Returns the shortest path to the first node `goal` accepts, `None` when none is reachable.
+pub fn bfs<N, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, usize>>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut parents: HashMap<N, Option<N>> = HashMap::from([(start.clone(), None)]);
+    let mut queue = VecDeque::from([start]);
+    while let Some(node) = queue.pop_front() {
+        if goal(&node) {
+            let nodes = walk_back(node, |node| parents[node].clone());
+            let cost = nodes.len() - 1;
+            return Some(Path { nodes, cost });
+        }
+        for next in successors(&node) {
+            if !parents.contains_key(&next) {
+                parents.insert(next.clone(), Some(node.clone()));
+                queue.push_back(next);
+            }
+        }
+    }
+    None
+}
+
+/// Cheapest path search, `successors` giving each neighbour with the cost of moving to it.
+///
+/// Costs must not be negative. `C::default()` is the cost of the empty path.
+pub fn dijkstra<N, C, I>(
+    start: N,
+    successors: impl FnMut(&N) -> I,
+    goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(start, successors, |_| C::default(), goal)
+}
+
+/// Dijkstra guided by `heuristic`, an estimate of the remaining cost to a goal.
+///
+/// The path is the cheapest one as long as `heuristic` never overestimates, like the Manhattan
+/// distance on a grid with 4-neighbour moves of cost 1 or more.
+pub fn astar<N, C, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let zero = C::default();
+    // Nodes live in `seen` so the heap only orders costs and indices, and `N` needs no `Ord`.
+    let mut seen: Vec<N> = vec![start.clone()];
+    let mut best: HashMap<N, (C, Option<usize>)> = HashMap::from([(start.clone(), (zero, None))]);
+    let mut frontier = BinaryHeap::from([Reverse((heuristic(&start), zero, 0))]);
+
+    while let Some(Reverse((_, cost, index))) = frontier.pop() {
+        let node = seen[index].clone();
+        if best[&node].0 < cost {
+            continue;
+        }
+        if goal(&node) {
+            let nodes = walk_back(node, |node| best[node].1.map(|parent| seen[parent].clone()));
+            return Some(Path { nodes, cost });
+        }
+        for (next, step) in successors(&node) {
+            let next_cost = cost + step;
+            if best
+                .get(&next)
+                .is_some_and(|&(known, _)| known <= next_cost)
+            {
+                continue;
+            }
+            best.insert(next.clone(), (next_cost, Some(index)));
+            frontier.push(Reverse((
+                next_cost + heuristic(&next),
+                next_cost,
+                seen.len(),
+            )));
+            seen.push(next);
+        }
+    }
+    None
+}
+
+/// Every node reachable from `start`, `start` included.
+pub fn flood_fill<N, I>(start: N, mut successors: impl FnMut(&N) -> I) -> HashSet<N>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut reached = HashSet::from([start.clone()]);
+    let mut pending = vec![start];
+    while let Some(node) = pending.pop() {
+        for next in successors(&node) {
+            if reached.insert(next.clone()) {
+                pending.push(next);
+            }
+        }
+    }
+    reached
+}
+
+/// Splits `nodes` into groups reachable from one another, in the order their first node appears.
+///
+/// `successors` should be symmetric, otherwise a group holds what its first node reaches.
+pub fn connected_components<N, I>(
+    nodes: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+) -> Vec<HashSet<N>>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut components: Vec<HashSet<N>> = Vec::new();
+    let mut assigned: HashSet<N> = HashSet::new();
+    for node in nodes {
+        if !assigned.contains(&node) {
+            let component = flood_fill(node, &mut successors);
+            assigned.extend(component.iter().cloned());
+            components.push(component);
+        }
+    }
+    components
+}
+
+/// Follows `parent` links from `end` and returns the nodes from the start.
+fn walk_back<N>(end: N, mut parent: impl FnMut(&N) -> Option<N>) -> Vec<N> {
+    let mut nodes = vec![end];
+    while let Some(previous) = parent(nodes.last().expect("starts with the end")) {
+        nodes.push(previous);
+    }
+    nodes.reverse();
+    nodes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::Point;
+    use crate::grid::Grid;
+
+    const MAZE: [&str; 5] = ["S..#....", ".#.#.##.", ".#...#..", ".####.#.", "......#E"];
+
+    fn maze() -> Grid<char> {
+        Grid::parse(MAZE, Some).unwrap()
+    }
+
+    fn open_neighbours(grid: &Grid<char>, point: &Point) -> Vec<Point> {
+        grid.neighbours4(*point)
+            .filter(|(_, cell)| **cell != '#')
+            .map(|(point, _)| point)
+            .collect()
+    }
+
+    #[test]
+    fn bfs_finds_shortest_path() {
+        let grid = maze();
+        let end = Point::new(7, 4);
+
+        let path = bfs(
+            Point::ORIGIN,
+            |point| open_neighbours(&grid, point),
+            |point| *point == end,
+        )
+        .unwrap();
+
+        assert_eq!(path.cost(), 15);
+        assert_eq!(path.steps(), 15);
+        assert_eq!((*path.start(), *path.end()), (Point::ORIGIN, end));
+        assert!(path
+            .nodes()
+            .windows(2)
+            .all(|pair| pair[0].manhattan(pair[1]) == 1));
+    }
+
+    #[test]
+    fn bfs_when_goal_unreachable_returns_none() {
+        let result = bfs(
+            0u32,
+            |&node| (node < 10).then_some(node + 1),
+            |&node| node == 20,
+        );
+
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn bfs_when_start_is_goal_returns_empty_path() {
+        let path = bfs('a', |_| None, |_| true).unwrap();
+
+        assert_eq!(path.into_nodes(), ['a']);
+    }
+
+    #[test]
+    fn dijkstra_prefers_cheaper_longer_path() {
+        let edges = HashMap::from([
+            ("a", vec![("b", 10), ("c", 1)]),
+            ("c", vec![("d", 1)]),
+            ("d", vec![("b", 1)]),
+        ]);
+
+        let path = dijkstra(
+            "a",
+            |node| edges.get(node).cloned().unwrap_or_default(),
+            |node| *node == "b",
+        )
+        .unwrap();
+
+        assert_eq!(path.nodes(), ["a", "c", "d", "b"]);
+        assert_eq!(path.cost(), 3);
+    }
+
+    #[test]
+    fn astar_matches_dijkstra_cost() {
+        let costs =
+            Grid::parse(["1163", "1381", "2136", "3694"], |digit| digit.to_digit(10)).unwrap();
+        let end = Point::new(3, 3);
+        let successors = |point: &Point| {
+            costs
+                .neighbours4(*point)
+                .map(|(point, cost)| (point, *cost))
+                .collect::<Vec<_>>()
+        };
+
+        let guided = astar(
+            Point::ORIGIN,
+            successors,
+            |point| point.manhattan(end) as u32,
+            |point| *point == end,
+        )
+        .unwrap();
+        let plain = dijkstra(Point::ORIGIN, successors, |point| *point == end).unwrap();
+
+        assert_eq!(guided.cost(), 17);
+        assert_eq!(plain.cost(), 17);
+    }
+
+    #[test]
+    fn flood_fill_stays_behind_walls() {
+        let grid = maze();
+
+        let reached = flood_fill(Point::ORIGIN, |point| open_neighbours(&grid, point));
+
+        let open = grid.iter().filter(|(_, cell)| **cell != '#').count();
+        assert_eq!(reached.len(), open);
+    }
+
+    #[test]
+    fn connected_components_groups_touching_cells() {
+        let grid = Grid::parse(["##..#", "#...#", "...##"], Some).unwrap();
+        let walls: Vec<Point> = grid
+            .iter()
+            .filter(|(_, cell)| **cell == '#')
+            .map(|(point, _)| point)
+            .collect();
+
+        let components = connected_components(walls, |point| {
+            grid.neighbours4(*point)
+                .filter(|(_, cell)| **cell == '#')
+                .map(|(point, _)| point)
+                .collect::<Vec<_>>()
+        });
+
+        let sizes: Vec<usize> = components.iter().map(HashSet::len).collect();
+        assert_eq!(sizes, [3, 4]);
+    }
+}
@@ -0,0 +1,129 @@
+use std::fmt::{self, Display};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use crate::Error;
+
+/// A single day of the puzzle calendar.
+///
+/// `parse` turns the raw puzzle input into the day's model once, after which both parts are
+/// answered from that model. A part fails when its answer cannot be computed, like a total that
+/// overflows. Crates that only solve one part of a day keep the default for the
+/// other part, which answers [`Unsolved`].
+pub trait Solution {
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, Error>;
+
+    fn part1(_input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(Unsolved)
+    }
+
+    fn part2(_input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(Unsolved)
+    }
+}
+
+/// Answer of a part that has not been solved yet.
+pub struct Unsolved;
+
+impl Display for Unsolved {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unsolved")
+    }
+}
+
+pub fn solve_part1<S: Solution>(input: &str) -> Result<String, Error> {
+    let parsed = S::parse(input)?;
+    let answer = S::part1(&parsed)?.to_string();
+    Ok(answer)
+}
+
+pub fn solve_part2<S: Solution>(input: &str) -> Result<String, Error> {
+    let parsed = S::parse(input)?;
+    let answer = S::part2(&parsed)?.to_string();
+    Ok(answer)
+}
+
+/// Time spent parsing the input and answering one part from the parsed model.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PhaseTimings {
+    pub parse: Duration,
+    pub solve: Duration,
+}
+
+pub fn time_part1<S: Solution>(input: &str) -> Result<PhaseTimings, Error> {
+    let (_, timings) = measure::<S>(input, |parsed| Ok(S::part1(parsed)?.to_string()))?;
+    Ok(timings)
+}
+
+pub fn time_part2<S: Solution>(input: &str) -> Result<PhaseTimings, Error> {
+    let (_, timings) = measure::<S>(input, |parsed| Ok(S::part2(parsed)?.to_string()))?;
+    Ok(timings)
+}
+
+/// Parses `input` and answers one part with `solve`, timing both phases.
+pub(crate) fn measure<S: Solution>(
+    input: &str,
+    solve: impl Fn(&S::Input) -> Result<String, Error>,
+) -> Result<(String, PhaseTimings), Error> {
+    let start = Instant::now();
+    let parsed = S::parse(black_box(input))?;
+    let parse = start.elapsed();
+
+    let start = Instant::now();
+    let answer = black_box(solve(&parsed))?;
+    let solve = start.elapsed();
+
+    Ok((answer, PhaseTimings { parse, solve }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{checked_sum, parse_lines, Token};
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input = Vec<i32>;
+
+        fn parse(input: &str) -> Result<Self::Input, Error> {
+            let numbers = parse_lines(input, |line| Token::new(line).parse_number("a number"))?;
+            Ok(numbers)
+        }
+
+        fn part1(input: &Self::Input) -> Result<impl Display, Error> {
+            Ok(checked_sum(input.iter().copied(), "sum")?)
+        }
+    }
+
+    #[test]
+    fn solve_part1_parses_and_solves() {
+        assert_eq!(solve_part1::<Sum>("1\n2\n3").unwrap(), "6");
+    }
+
+    #[test]
+    fn solve_part1_when_parse_fails_returns_error() {
+        let result = solve_part1::<Sum>("1\nx");
+
+        assert!(matches!(result, Err(Error::Parse(error)) if error.line() == 2));
+    }
+
+    #[test]
+    fn solve_part1_when_part_fails_returns_error() {
+        let result = solve_part1::<Sum>("2147483647\n1");
+
+        assert!(matches!(result, Err(Error::Overflow(_))));
+    }
+
+    #[test]
+    fn time_part1_when_parse_fails_returns_error() {
+        assert!(time_part1::<Sum>("x").is_err());
+    }
+
+    #[test]
+    fn solve_part2_when_not_implemented_returns_unsolved() {
+        assert_eq!(solve_part2::<Sum>("1").unwrap(), "unsolved");
+    }
+}
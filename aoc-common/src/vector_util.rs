@@ -0,0 +1,194 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+/// Which end of the ordering [`top_k`] selects from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Order {
+    /// The largest items, largest first.
+    #[default]
+    Descending,
+    /// The smallest items, smallest first.
+    Ascending,
+}
+
+/// The first `k` items in `order`, in O(n log k) time and O(k) memory.
+///
+/// Returns every item, sorted, when there are fewer than `k`. Equal items keep the order they
+/// came in.
+pub fn top_k<T: Ord>(items: impl IntoIterator<Item = T>, k: usize, order: Order) -> Vec<T> {
+    select(items, k, order)
+        .into_iter()
+        .map(|(_, item)| item)
+        .collect()
+}
+
+/// Like [`top_k`], but returns the positions of the selected items in `items`.
+pub fn top_k_indices<T: Ord>(
+    items: impl IntoIterator<Item = T>,
+    k: usize,
+    order: Order,
+) -> Vec<usize> {
+    select(items, k, order)
+        .into_iter()
+        .map(|(index, _)| index)
+        .collect()
+}
+
+fn select<T: Ord>(items: impl IntoIterator<Item = T>, k: usize, order: Order) -> Vec<(usize, T)> {
+    let mut top = TopK::new(k, order);
+    top.extend(items);
+    top.into_indexed()
+}
+
+/// The first `k` items in `order` among those pushed so far, for items that arrive one at a
+/// time. It never holds more than `k` of them.
+///
+/// Equal items keep the order they were pushed in, as with [`top_k`].
+#[derive(Debug, Clone)]
+pub struct TopK<T> {
+    k: usize,
+    pushed: usize,
+    heap: Heap<T>,
+}
+
+/// A heap whose top is the worst of the items kept, so it is the one dropped.
+#[derive(Debug, Clone)]
+enum Heap<T> {
+    // A later index ranks lower, so ties are dropped from the end first.
+    Descending(BinaryHeap<Reverse<(T, Reverse<usize>)>>),
+    Ascending(BinaryHeap<(T, usize)>),
+}
+
+impl<T: Ord> TopK<T> {
+    pub fn new(k: usize, order: Order) -> Self {
+        let heap = match order {
+            Order::Descending => Heap::Descending(BinaryHeap::with_capacity(k + 1)),
+            Order::Ascending => Heap::Ascending(BinaryHeap::with_capacity(k + 1)),
+        };
+        TopK { k, pushed: 0, heap }
+    }
+
+    pub fn push(&mut self, item: T) {
+        let index = self.pushed;
+        self.pushed += 1;
+        match &mut self.heap {
+            Heap::Descending(heap) => {
+                heap.push(Reverse((item, Reverse(index))));
+                if heap.len() > self.k {
+                    heap.pop();
+                }
+            }
+            Heap::Ascending(heap) => {
+                heap.push((item, index));
+                if heap.len() > self.k {
+                    heap.pop();
+                }
+            }
+        }
+    }
+
+    /// Number of items kept, at most `k`.
+    pub fn len(&self) -> usize {
+        match &self.heap {
+            Heap::Descending(heap) => heap.len(),
+            Heap::Ascending(heap) => heap.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The items kept, in `order`.
+    pub fn into_sorted_vec(self) -> Vec<T> {
+        self.into_indexed()
+            .into_iter()
+            .map(|(_, item)| item)
+            .collect()
+    }
+
+    /// The items kept with the position they were pushed at, in `order`.
+    fn into_indexed(self) -> Vec<(usize, T)> {
+        match self.heap {
+            Heap::Descending(heap) => heap
+                .into_sorted_vec()
+                .into_iter()
+                .map(|Reverse((item, Reverse(index)))| (index, item))
+                .collect(),
+            Heap::Ascending(heap) => heap
+                .into_sorted_vec()
+                .into_iter()
+                .map(|(item, index)| (index, item))
+                .collect(),
+        }
+    }
+}
+
+impl<T: Ord> Extend<T> for TopK<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, items: I) {
+        for item in items {
+            self.push(item);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn top_k_descending_returns_largest_first() {
+        let result = top_k([1, 5, 2, 4, 3], 3, Order::Descending);
+
+        assert_eq!(result, [5, 4, 3]);
+    }
+
+    #[test]
+    fn top_k_ascending_returns_smallest_first() {
+        let result = top_k([1, 5, 2, 4, 3], 2, Order::Ascending);
+
+        assert_eq!(result, [1, 2]);
+    }
+
+    #[test]
+    fn top_k_doesnt_alter_original() {
+        let input: Vec<i64> = vec![1, 2, 3, 4, 5];
+
+        top_k(&input, 3, Order::Descending);
+
+        assert_eq!(input, [1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn top_k_when_k_exceeds_length_returns_everything_sorted() {
+        let result = top_k([2, 3, 1], 10, Order::Descending);
+
+        assert_eq!(result, [3, 2, 1]);
+    }
+
+    #[test]
+    fn top_k_when_k_is_zero_returns_nothing() {
+        assert!(top_k([1, 2], 0, Order::Descending).is_empty());
+        assert!(top_k(Vec::<i32>::new(), 3, Order::Ascending).is_empty());
+    }
+
+    #[test]
+    fn top_k_indices_keeps_input_order_for_ties() {
+        let values = [7, 9, 7, 1, 9];
+
+        assert_eq!(top_k_indices(values, 3, Order::Descending), [1, 4, 0]);
+        assert_eq!(top_k_indices(values, 3, Order::Ascending), [3, 0, 2]);
+    }
+
+    #[test]
+    fn top_k_accumulator_keeps_only_k_items() {
+        let mut top = TopK::new(2, Order::Descending);
+
+        for item in [4, 9, 1, 9, 7] {
+            top.push(item);
+            assert!(top.len() <= 2);
+        }
+
+        assert_eq!(top.into_sorted_vec(), [9, 9]);
+    }
+}
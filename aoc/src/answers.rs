@@ -0,0 +1,105 @@
+use std::path::Path;
+
+use aoc_common::{parse_lines, read_input, Error, ParseError, Tokens};
+
+/// Expected answer for one part of a day, run against one of its input files.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answer {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub input: String,
+    pub answer: String,
+}
+
+/// The answers manifest, one `year day part input answer` line per answer.
+///
+/// Blank lines and lines starting with `#` are ignored.
+#[derive(Debug, Default)]
+pub struct Answers {
+    answers: Vec<Answer>,
+}
+
+impl Answers {
+    pub fn load(path: impl AsRef<Path>) -> Result<Answers, Error> {
+        let text = read_input(path)?;
+        let answers = Answers::parse(&text)?;
+        Ok(answers)
+    }
+
+    pub fn parse(text: &str) -> Result<Answers, ParseError> {
+        let lines = parse_lines(text, |line| {
+            if line.trim().is_empty() || line.starts_with('#') {
+                return Ok(None);
+            }
+            parse_answer(line).map(Some)
+        })?;
+
+        Ok(Answers {
+            answers: lines.into_iter().flatten().collect(),
+        })
+    }
+
+    pub fn get(&self, year: u16, day: u8, part: u8, input: &str) -> Option<&str> {
+        self.answers
+            .iter()
+            .find(|answer| {
+                answer.year == year
+                    && answer.day == day
+                    && answer.part == part
+                    && answer.input == input
+            })
+            .map(|answer| answer.answer.as_str())
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Answer> {
+        self.answers.iter()
+    }
+}
+
+fn parse_answer(line: &str) -> Result<Answer, ParseError> {
+    let mut tokens = Tokens::new(line, ' ');
+    let year = tokens.expect("a year")?.parse_number("a year")?;
+    let day = tokens.expect("a day")?.parse_number("a day")?;
+    let part = tokens.expect("a part")?.parse_number("a part")?;
+    let input = tokens.expect("an input file name")?;
+    let answer = tokens.expect("an answer")?;
+    tokens.finish("end of line")?;
+
+    Ok(Answer {
+        year,
+        day,
+        part,
+        input: String::from(input.text),
+        answer: String::from(answer.text),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::ParseErrorKind;
+
+    #[test]
+    fn parse_skips_comments_and_blank_lines() {
+        let answers = Answers::parse("# header\n\n2022 1 1 input.txt 24000\n").unwrap();
+
+        assert_eq!(answers.iter().count(), 1);
+        assert_eq!(answers.get(2022, 1, 1, "input.txt"), Some("24000"));
+    }
+
+    #[test]
+    fn get_when_other_input_returns_none() {
+        let answers = Answers::parse("2022 5 2 input.txt MCD").unwrap();
+
+        assert_eq!(answers.get(2022, 5, 2, "input_test.txt"), None);
+    }
+
+    #[test]
+    fn parse_when_answer_missing_reports_line() {
+        let result = Answers::parse("# header\n2022 1 1 input.txt").unwrap_err();
+
+        assert_eq!(result.kind(), ParseErrorKind::MissingToken);
+        assert_eq!(result.line(), 2);
+    }
+}
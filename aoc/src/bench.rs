@@ -0,0 +1,332 @@
+use std::fmt::{self, Write as _};
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+use aoc_common::{parse_lines, read_input, Error, ParseError, Tokens};
+
+use crate::registry::Entry;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+    Parse,
+    Solve,
+}
+
+impl Phase {
+    fn from_name(name: &str) -> Option<Phase> {
+        match name {
+            "parse" => Some(Phase::Parse),
+            "solve" => Some(Phase::Solve),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Phase::Parse => write!(f, "parse"),
+            Phase::Solve => write!(f, "solve"),
+        }
+    }
+}
+
+/// Median, fastest and slowest of a series of runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub median: Duration,
+    pub min: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    /// Returns `None` when there are no samples.
+    pub fn from_samples(mut samples: Vec<Duration>) -> Option<Stats> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        samples.sort();
+        let middle = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            (samples[middle - 1] + samples[middle]) / 2
+        } else {
+            samples[middle]
+        };
+
+        Some(Stats {
+            median,
+            min: samples[0],
+            max: samples[samples.len() - 1],
+        })
+    }
+}
+
+/// Timings of one phase of one part of a day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Measurement {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub phase: Phase,
+    pub stats: Stats,
+}
+
+impl Measurement {
+    fn same_key(&self, year: u16, day: u8, part: u8, phase: Phase) -> bool {
+        self.year == year && self.day == day && self.part == part && self.phase == phase
+    }
+}
+
+/// Runs the entry `iterations` times on `input` and returns the parse and solve timings.
+pub fn bench(entry: &Entry, input: &str, iterations: usize) -> Result<[Measurement; 2], Error> {
+    let mut parse = Vec::with_capacity(iterations);
+    let mut solve = Vec::with_capacity(iterations);
+    for _ in 0..iterations.max(1) {
+        let timings = (entry.time)(input)?;
+        parse.push(timings.parse);
+        solve.push(timings.solve);
+    }
+
+    let measurement = |phase, samples| Measurement {
+        year: entry.year,
+        day: entry.day,
+        part: entry.part,
+        phase,
+        stats: Stats::from_samples(samples).expect("at least one iteration is run"),
+    };
+    Ok([
+        measurement(Phase::Parse, parse),
+        measurement(Phase::Solve, solve),
+    ])
+}
+
+/// Median timings of an earlier run, one `year day part phase nanoseconds` line per measurement.
+#[derive(Debug, Default)]
+pub struct Baseline {
+    medians: Vec<(u16, u8, u8, Phase, Duration)>,
+}
+
+impl Baseline {
+    pub fn load(path: impl AsRef<Path>) -> Result<Baseline, Error> {
+        let text = read_input(path)?;
+        let baseline = Baseline::parse(&text)?;
+        Ok(baseline)
+    }
+
+    pub fn parse(text: &str) -> Result<Baseline, ParseError> {
+        let medians = parse_lines(text, |line| {
+            if line.trim().is_empty() {
+                return Ok(None);
+            }
+
+            let mut tokens = Tokens::new(line, ' ');
+            let year = tokens.expect("a year")?.parse_number("a year")?;
+            let day = tokens.expect("a day")?.parse_number("a day")?;
+            let part = tokens.expect("a part")?.parse_number("a part")?;
+            let phase_token = tokens.expect("a phase")?;
+            let phase = Phase::from_name(phase_token.text)
+                .ok_or_else(|| phase_token.unexpected("one of parse or solve"))?;
+            let nanos = tokens
+                .expect("a median in nanoseconds")?
+                .parse_number("a median in nanoseconds")?;
+            tokens.finish("end of line")?;
+
+            Ok(Some((year, day, part, phase, Duration::from_nanos(nanos))))
+        })?;
+
+        Ok(Baseline {
+            medians: medians.into_iter().flatten().collect(),
+        })
+    }
+
+    /// Replaces the medians of the measured phases, keeping those that were not measured.
+    pub fn record(&mut self, measurements: &[Measurement]) {
+        for m in measurements {
+            self.medians
+                .retain(|&(year, day, part, phase, _)| !m.same_key(year, day, part, phase));
+            self.medians
+                .push((m.year, m.day, m.part, m.phase, m.stats.median));
+        }
+    }
+
+    pub fn median(&self, measurement: &Measurement) -> Option<Duration> {
+        self.medians
+            .iter()
+            .find(|&&(year, day, part, phase, _)| measurement.same_key(year, day, part, phase))
+            .map(|&(.., median)| median)
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> std::io::Result<()> {
+        let mut text = String::new();
+        for (year, day, part, phase, median) in &self.medians {
+            let _ = writeln!(text, "{year} {day} {part} {phase} {}", median.as_nanos());
+        }
+        fs::write(path, text)
+    }
+}
+
+/// Whether `current` is slower than `baseline` by more than `threshold` percent.
+///
+/// A zero baseline has no percentage to compare against, so it never makes a regression.
+pub fn is_regression(current: Duration, baseline: Duration, threshold: f64) -> bool {
+    !baseline.is_zero()
+        && current.as_secs_f64() > baseline.as_secs_f64() * (1.0 + threshold / 100.0)
+}
+
+/// Relative change from `baseline` to `current` in percent, `None` when the baseline is zero.
+pub fn percent_change(current: Duration, baseline: Duration) -> Option<f64> {
+    if baseline.is_zero() {
+        return None;
+    }
+    Some((current.as_secs_f64() / baseline.as_secs_f64() - 1.0) * 100.0)
+}
+
+pub fn print_table(measurements: &[Measurement], baseline: &Baseline, threshold: f64) -> usize {
+    let mut regressions = 0;
+
+    println!(
+        "{:<4}  {:<3}  {:<4}  {:<5}  {:>12}  {:>12}  {:>12}  {:>12}  change",
+        "year", "day", "part", "phase", "median", "min", "max", "baseline"
+    );
+    for measurement in measurements {
+        let stats = measurement.stats;
+        let (previous, change) = match baseline.median(measurement) {
+            Some(previous) => {
+                let mut change = match percent_change(stats.median, previous) {
+                    Some(percent) => format!("{percent:+.1}%"),
+                    None => String::from("n/a"),
+                };
+                if is_regression(stats.median, previous, threshold) {
+                    regressions += 1;
+                    change.push_str(" REGRESSION");
+                }
+                (format!("{previous:.1?}"), change)
+            }
+            None => (String::from("-"), String::from("-")),
+        };
+
+        println!(
+            "{:<4}  {:<3}  {:<4}  {:<5}  {:>12}  {:>12}  {:>12}  {:>12}  {}",
+            measurement.year,
+            format!("{:02}", measurement.day),
+            measurement.part,
+            measurement.phase,
+            format!("{:.1?}", stats.median),
+            format!("{:.1?}", stats.min),
+            format!("{:.1?}", stats.max),
+            previous,
+            change
+        );
+    }
+
+    regressions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn millis(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|&ms| Duration::from_millis(ms)).collect()
+    }
+
+    #[test]
+    fn from_samples_when_odd_count_takes_middle() {
+        let stats = Stats::from_samples(millis(&[5, 1, 3])).unwrap();
+
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.max, Duration::from_millis(5));
+    }
+
+    #[test]
+    fn from_samples_when_even_count_averages_middle_pair() {
+        let stats = Stats::from_samples(millis(&[4, 1, 2, 8])).unwrap();
+
+        assert_eq!(stats.median, Duration::from_millis(3));
+    }
+
+    #[test]
+    fn from_samples_when_empty_returns_none() {
+        assert_eq!(Stats::from_samples(Vec::new()), None);
+    }
+
+    #[test]
+    fn baseline_round_trips_through_text() {
+        let measurement = Measurement {
+            year: 2022,
+            day: 4,
+            part: 2,
+            phase: Phase::Solve,
+            stats: Stats::from_samples(millis(&[2])).unwrap(),
+        };
+        let path = std::env::temp_dir().join(format!("aoc-bench-{}", std::process::id()));
+
+        let mut baseline = Baseline::default();
+        baseline.record(std::slice::from_ref(&measurement));
+        baseline.save(&path).unwrap();
+        let loaded = Baseline::load(&path).unwrap();
+
+        assert_eq!(loaded.median(&measurement), Some(Duration::from_millis(2)));
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn record_keeps_phases_that_were_not_measured() {
+        let mut baseline = Baseline::parse("2022 1 1 parse 100\n2022 1 1 solve 200").unwrap();
+        let measurement = Measurement {
+            year: 2022,
+            day: 1,
+            part: 1,
+            phase: Phase::Solve,
+            stats: Stats::from_samples(vec![Duration::from_nanos(150)]).unwrap(),
+        };
+
+        baseline.record(std::slice::from_ref(&measurement));
+
+        assert_eq!(baseline.medians.len(), 2);
+        assert_eq!(
+            baseline.median(&measurement),
+            Some(Duration::from_nanos(150))
+        );
+    }
+
+    #[test]
+    fn baseline_when_unknown_phase_returns_error() {
+        let result = Baseline::parse("2022 4 2 warmup 100").unwrap_err();
+
+        assert_eq!(result.span(), 9..15);
+    }
+
+    #[test]
+    fn is_regression_uses_threshold_percent() {
+        let baseline = Duration::from_millis(100);
+
+        assert!(!is_regression(Duration::from_millis(109), baseline, 10.0));
+        assert!(is_regression(Duration::from_millis(111), baseline, 10.0));
+    }
+
+    #[test]
+    fn is_regression_when_baseline_zero_is_false() {
+        assert!(!is_regression(
+            Duration::from_nanos(40),
+            Duration::ZERO,
+            10.0
+        ));
+        assert!(!is_regression(Duration::ZERO, Duration::ZERO, 10.0));
+    }
+
+    #[test]
+    fn percent_change_when_baseline_zero_returns_none() {
+        assert_eq!(
+            percent_change(Duration::from_nanos(40), Duration::ZERO),
+            None
+        );
+        assert_eq!(
+            percent_change(Duration::from_millis(200), Duration::from_millis(100)),
+            Some(100.0)
+        );
+    }
+}
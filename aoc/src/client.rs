@@ -0,0 +1,94 @@
+use std::fmt;
+use std::time::Duration;
+
+use ureq::Agent;
+
+/// Status and body of an HTTP response.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+/// Failure to get any response from the server.
+#[derive(Debug)]
+pub struct TransportError(String);
+
+impl fmt::Display for TransportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "request failed: {}", self.0)
+    }
+}
+
+impl std::error::Error for TransportError {}
+
+/// The HTTP requests made to the puzzle site, authenticated with a session cookie.
+pub trait HttpClient {
+    fn get(&self, url: &str, session: &str) -> Result<Response, TransportError>;
+
+    /// Posts `form` as `application/x-www-form-urlencoded`.
+    fn post_form(
+        &self,
+        url: &str,
+        session: &str,
+        form: &[(&str, &str)],
+    ) -> Result<Response, TransportError>;
+}
+
+const USER_AGENT: &str = concat!("aoc-runner/", env!("CARGO_PKG_VERSION"));
+
+pub struct UreqClient {
+    agent: Agent,
+}
+
+impl UreqClient {
+    pub fn new() -> Self {
+        let agent = Agent::config_builder()
+            .http_status_as_error(false)
+            .timeout_global(Some(Duration::from_secs(30)))
+            .user_agent(USER_AGENT)
+            .build()
+            .into();
+        UreqClient { agent }
+    }
+}
+
+impl HttpClient for UreqClient {
+    fn get(&self, url: &str, session: &str) -> Result<Response, TransportError> {
+        let response = self
+            .agent
+            .get(url)
+            .header("Cookie", format!("session={session}"))
+            .call()
+            .map_err(|error| TransportError(error.to_string()))?;
+
+        read_response(response)
+    }
+
+    fn post_form(
+        &self,
+        url: &str,
+        session: &str,
+        form: &[(&str, &str)],
+    ) -> Result<Response, TransportError> {
+        let response = self
+            .agent
+            .post(url)
+            .header("Cookie", format!("session={session}"))
+            .send_form(form.iter().copied())
+            .map_err(|error| TransportError(error.to_string()))?;
+
+        read_response(response)
+    }
+}
+
+fn read_response(
+    mut response: ureq::http::Response<ureq::Body>,
+) -> Result<Response, TransportError> {
+    let status = response.status().as_u16();
+    let body = response
+        .body_mut()
+        .read_to_string()
+        .map_err(|error| TransportError(error.to_string()))?;
+    Ok(Response { status, body })
+}
@@ -0,0 +1,151 @@
+use std::env;
+use std::path::{Path, PathBuf};
+
+use aoc_common::{parse_lines, read_input, Error, ParseError, Token};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Settings for talking to the puzzle site.
+///
+/// Read from a `key = value` config file, then overridden by the `AOC_SESSION`, `AOC_BASE_URL`
+/// and `AOC_CACHE_DIR` environment variables. The file lives at `$AOC_CONFIG`, or
+/// `aoc/config` under the user's config directory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    pub session: Option<String>,
+    pub base_url: String,
+    pub cache_dir: PathBuf,
+}
+
+impl Config {
+    pub fn load() -> Result<Config, Error> {
+        let file = match config_path() {
+            Some(path) if path.exists() => Some(read_input(path)?),
+            _ => None,
+        };
+        let config = Config::from_sources(file.as_deref(), |key| env::var(key).ok())?;
+        Ok(config)
+    }
+
+    pub fn from_sources(
+        file: Option<&str>,
+        env: impl Fn(&str) -> Option<String>,
+    ) -> Result<Config, ParseError> {
+        let mut config = Config {
+            session: None,
+            base_url: String::from(DEFAULT_BASE_URL),
+            cache_dir: default_cache_dir(&env),
+        };
+
+        let entries = parse_lines(file.unwrap_or_default(), parse_entry)?;
+        for (key, value) in entries.into_iter().flatten() {
+            config.set(key, value);
+        }
+
+        for (variable, key) in [
+            ("AOC_SESSION", "session"),
+            ("AOC_BASE_URL", "base_url"),
+            ("AOC_CACHE_DIR", "cache_dir"),
+        ] {
+            if let Some(value) = env(variable).filter(|value| !value.is_empty()) {
+                config.set(key, value);
+            }
+        }
+
+        Ok(config)
+    }
+
+    fn set(&mut self, key: &str, value: String) {
+        match key {
+            "session" => self.session = Some(value),
+            "base_url" => self.base_url = value,
+            "cache_dir" => self.cache_dir = PathBuf::from(value),
+            _ => unreachable!("keys are checked while parsing"),
+        }
+    }
+}
+
+fn parse_entry(line: &str) -> Result<Option<(&'static str, String)>, ParseError> {
+    let trimmed = line.trim();
+    if trimmed.is_empty() || trimmed.starts_with('#') {
+        return Ok(None);
+    }
+
+    let Some((key, value)) = line.split_once('=') else {
+        return Err(ParseError::missing(line.len(), "'=' between key and value"));
+    };
+    let key = match key.trim() {
+        "session" => "session",
+        "base_url" => "base_url",
+        "cache_dir" => "cache_dir",
+        _ => {
+            let token = Token::new(key.trim_end());
+            return Err(token.unexpected("one of session, base_url or cache_dir"));
+        }
+    };
+    Ok(Some((key, String::from(value.trim()))))
+}
+
+fn config_path() -> Option<PathBuf> {
+    if let Some(path) = env::var_os("AOC_CONFIG") {
+        return Some(PathBuf::from(path));
+    }
+    let base = env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".config")))?;
+    Some(base.join("aoc").join("config"))
+}
+
+fn default_cache_dir(env: &impl Fn(&str) -> Option<String>) -> PathBuf {
+    let base = env("XDG_CACHE_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env("HOME").map(|home| Path::new(&home).join(".cache")))
+        .unwrap_or_else(std::env::temp_dir);
+    base.join("aoc")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn no_env(_: &str) -> Option<String> {
+        None
+    }
+
+    #[test]
+    fn from_sources_reads_file_entries() {
+        let file = "# comment\nsession = abc123\nbase_url = http://localhost:8080\n";
+
+        let config = Config::from_sources(Some(file), no_env).unwrap();
+
+        assert_eq!(config.session.as_deref(), Some("abc123"));
+        assert_eq!(config.base_url, "http://localhost:8080");
+    }
+
+    #[test]
+    fn from_sources_environment_overrides_file() {
+        let env = |key: &str| (key == "AOC_SESSION").then(|| String::from("from-env"));
+
+        let config = Config::from_sources(Some("session = from-file"), env).unwrap();
+
+        assert_eq!(config.session.as_deref(), Some("from-env"));
+        assert_eq!(config.base_url, DEFAULT_BASE_URL);
+    }
+
+    #[test]
+    fn from_sources_cache_dir_defaults_under_home() {
+        let env = |key: &str| (key == "HOME").then(|| String::from("/home/elf"));
+
+        let config = Config::from_sources(None, env).unwrap();
+
+        assert_eq!(config.cache_dir, PathBuf::from("/home/elf/.cache/aoc"));
+    }
+
+    #[test]
+    fn from_sources_when_unknown_key_returns_error() {
+        let result = Config::from_sources(Some("\ntoken = abc"), no_env).unwrap_err();
+
+        assert_eq!(result.line(), 2);
+        assert_eq!(result.found(), "token");
+    }
+}
@@ -0,0 +1,368 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use sha2::{Digest, Sha256};
+
+use crate::client::{HttpClient, Response, TransportError};
+
+/// Time to wait between two requests to the puzzle site.
+pub const DEFAULT_MIN_INTERVAL: Duration = Duration::from_secs(5);
+
+#[derive(Debug)]
+pub enum FetchError {
+    /// No session token was configured and the input is not cached.
+    MissingSession,
+    Transport(TransportError),
+    /// The server answered with something other than 200 OK.
+    Status {
+        status: u16,
+        body: String,
+    },
+    /// The server answered with a page that does not say what happened.
+    UnrecognisedResponse {
+        status: u16,
+    },
+    Cache {
+        path: PathBuf,
+        source: io::Error,
+    },
+}
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FetchError::MissingSession => write!(
+                f,
+                "no session token, set AOC_SESSION or 'session' in the config file"
+            ),
+            FetchError::Transport(error) => write!(f, "{error}"),
+            FetchError::Status { status, body } => {
+                write!(f, "server answered {status}: {}", body.trim())
+            }
+            FetchError::UnrecognisedResponse { status } => {
+                write!(f, "server answered {status} with an unrecognised page")
+            }
+            FetchError::Cache { path, source } => {
+                write!(f, "could not use cache {}: {source}", path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for FetchError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            FetchError::Transport(error) => Some(error),
+            FetchError::Cache { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl From<TransportError> for FetchError {
+    fn from(error: TransportError) -> Self {
+        FetchError::Transport(error)
+    }
+}
+
+/// Downloads puzzle inputs, keeping every input it fetched in `cache_dir`.
+///
+/// Cached inputs are kept apart per host and per session, as each account gets its own inputs.
+///
+/// Requests that do reach the server are spaced at least `min_interval` apart, also across
+/// runs, by remembering the time of the last request in the cache directory.
+pub struct Fetcher<C: HttpClient> {
+    client: C,
+    base_url: String,
+    session: Option<String>,
+    cache_dir: PathBuf,
+    min_interval: Duration,
+}
+
+impl<C: HttpClient> Fetcher<C> {
+    pub fn new(client: C, base_url: &str, session: Option<String>, cache_dir: PathBuf) -> Self {
+        Fetcher {
+            client,
+            base_url: String::from(base_url.trim_end_matches('/')),
+            session,
+            cache_dir,
+            min_interval: DEFAULT_MIN_INTERVAL,
+        }
+    }
+
+    #[cfg(test)]
+    pub fn with_min_interval(mut self, min_interval: Duration) -> Self {
+        self.min_interval = min_interval;
+        self
+    }
+
+    pub fn input(&self, year: u16, day: u8) -> Result<String, FetchError> {
+        let cached = self.input_path(year, day);
+        if cached.exists() {
+            return fs::read_to_string(&cached).map_err(|source| cache_error(&cached, source));
+        }
+
+        let session = self.session.as_deref().ok_or(FetchError::MissingSession)?;
+        let url = format!("{}/{year}/day/{day}/input", self.base_url);
+        let response = self.request(|| self.client.get(&url, session))?;
+        if response.status != 200 {
+            return Err(FetchError::Status {
+                status: response.status,
+                body: response.body,
+            });
+        }
+
+        let dir = cached.parent().expect("cache path has a year directory");
+        fs::create_dir_all(dir).map_err(|source| cache_error(dir, source))?;
+        fs::write(&cached, &response.body).map_err(|source| cache_error(&cached, source))?;
+        Ok(response.body)
+    }
+
+    /// Posts `form` to `path` under the base URL, with the same rate limit as downloads.
+    pub fn post(&self, path: &str, form: &[(&str, &str)]) -> Result<Response, FetchError> {
+        let session = self.session.as_deref().ok_or(FetchError::MissingSession)?;
+        let url = format!("{}{path}", self.base_url);
+        self.request(|| self.client.post_form(&url, session, form))
+    }
+
+    /// Directory holding the cached inputs and the state kept between runs.
+    #[cfg(test)]
+    pub fn cache_dir(&self) -> &Path {
+        &self.cache_dir
+    }
+
+    /// Directory for what belongs to one account on one host, under the host and a fingerprint
+    /// of the session: inputs, wrong guesses and cooldowns.
+    pub fn account_dir(&self) -> PathBuf {
+        self.cache_dir
+            .join(cache_host(&self.base_url))
+            .join(session_fingerprint(self.session.as_deref()))
+    }
+
+    /// Where the input of `day` is cached.
+    fn input_path(&self, year: u16, day: u8) -> PathBuf {
+        self.account_dir()
+            .join(year.to_string())
+            .join(format!("day{day:02}.txt"))
+    }
+
+    /// Runs `send` once the rate limit allows it and records when it happened.
+    fn request<T>(
+        &self,
+        send: impl FnOnce() -> Result<T, TransportError>,
+    ) -> Result<T, FetchError> {
+        let stamp = self.cache_dir.join("last-request");
+        let last = fs::read_to_string(&stamp)
+            .ok()
+            .and_then(|millis| millis.trim().parse().ok())
+            .map(|millis| UNIX_EPOCH + Duration::from_millis(millis));
+        thread::sleep(remaining_wait(last, SystemTime::now(), self.min_interval));
+
+        let result = send();
+
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis();
+        fs::create_dir_all(&self.cache_dir)
+            .map_err(|source| cache_error(&self.cache_dir, source))?;
+        fs::write(&stamp, now.to_string()).map_err(|source| cache_error(&stamp, source))?;
+
+        Ok(result?)
+    }
+}
+
+/// `base_url` without its scheme, with anything unsafe in a directory name replaced.
+fn cache_host(base_url: &str) -> String {
+    let host = base_url
+        .split_once("://")
+        .map_or(base_url, |(_, rest)| rest);
+    host.chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '.' || c == '-' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+/// First 16 hex digits of the SHA-256 of `session`, so the token itself is never written.
+fn session_fingerprint(session: Option<&str>) -> String {
+    let Some(session) = session else {
+        return String::from("no-session");
+    };
+    Sha256::digest(session.as_bytes())
+        .iter()
+        .take(8)
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+fn cache_error(path: &Path, source: io::Error) -> FetchError {
+    FetchError::Cache {
+        path: path.to_path_buf(),
+        source,
+    }
+}
+
+/// How long to wait so that at least `min_interval` separates `last` from the next request.
+pub fn remaining_wait(
+    last: Option<SystemTime>,
+    now: SystemTime,
+    min_interval: Duration,
+) -> Duration {
+    let Some(last) = last else {
+        return Duration::ZERO;
+    };
+    let elapsed = now.duration_since(last).unwrap_or_default();
+    min_interval.saturating_sub(elapsed)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+    use crate::client::UreqClient;
+    use crate::stub_server::StubServer;
+
+    fn cache_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-fetch-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn fetcher(server: &StubServer, cache: &Path) -> Fetcher<UreqClient> {
+        Fetcher::new(
+            UreqClient::new(),
+            &server.base_url,
+            Some(String::from("secret")),
+            cache.to_path_buf(),
+        )
+        .with_min_interval(Duration::ZERO)
+    }
+
+    #[test]
+    fn input_requests_day_with_session_cookie() {
+        let server = StubServer::start(vec![(200, "1000\n2000\n")]);
+        let cache = cache_dir("cookie");
+
+        let input = fetcher(&server, &cache).input(2022, 1).unwrap();
+
+        assert_eq!(input, "1000\n2000\n");
+        let requests = server.requests();
+        assert_eq!(requests[0].method, "GET");
+        assert_eq!(requests[0].path, "/2022/day/1/input");
+        assert_eq!(requests[0].cookie.as_deref(), Some("session=secret"));
+        fs::remove_dir_all(cache).unwrap();
+    }
+
+    #[test]
+    fn input_when_cached_does_not_request_again() {
+        let server = StubServer::start(vec![(200, "A Y\n")]);
+        let cache = cache_dir("cached");
+        let fetcher = fetcher(&server, &cache);
+
+        fetcher.input(2022, 2).unwrap();
+        let second = fetcher.input(2022, 2).unwrap();
+
+        assert_eq!(second, "A Y\n");
+        assert_eq!(server.requests().len(), 1);
+        assert!(fetcher.input_path(2022, 2).exists());
+        fs::remove_dir_all(cache).unwrap();
+    }
+
+    #[test]
+    fn input_when_base_url_changes_misses_cache() {
+        let first = StubServer::start(vec![(200, "A Y\n")]);
+        let second = StubServer::start(vec![(200, "B X\n")]);
+        let cache = cache_dir("other-host");
+
+        fetcher(&first, &cache).input(2022, 2).unwrap();
+        let input = fetcher(&second, &cache).input(2022, 2).unwrap();
+
+        assert_eq!(input, "B X\n");
+        assert_eq!(second.requests().len(), 1);
+        fs::remove_dir_all(cache).unwrap();
+    }
+
+    #[test]
+    fn input_when_session_changes_misses_cache() {
+        let server = StubServer::start(vec![(200, "A Y\n"), (200, "B X\n")]);
+        let cache = cache_dir("other-session");
+        fetcher(&server, &cache).input(2022, 2).unwrap();
+        let other = Fetcher::new(
+            UreqClient::new(),
+            &server.base_url,
+            Some(String::from("another")),
+            cache.clone(),
+        )
+        .with_min_interval(Duration::ZERO);
+
+        let input = other.input(2022, 2).unwrap();
+
+        assert_eq!(input, "B X\n");
+        assert_eq!(server.requests().len(), 2);
+        fs::remove_dir_all(cache).unwrap();
+    }
+
+    #[test]
+    fn cache_key_never_holds_the_session() {
+        let fetcher = Fetcher::new(
+            UreqClient::new(),
+            "https://adventofcode.com/",
+            Some(String::from("secret")),
+            PathBuf::from("cache"),
+        );
+
+        let path = fetcher.input_path(2022, 1);
+
+        assert!(path.starts_with("cache/adventofcode.com"));
+        assert!(!path.to_string_lossy().contains("secret"));
+        assert!(path.ends_with("2022/day01.txt"));
+    }
+
+    #[test]
+    fn input_when_not_found_returns_status_and_caches_nothing() {
+        let server = StubServer::start(vec![(404, "Not yet unlocked")]);
+        let cache = cache_dir("not-found");
+
+        let result = fetcher(&server, &cache).input(2022, 25).unwrap_err();
+
+        assert!(matches!(result, FetchError::Status { status: 404, .. }));
+        assert!(!fetcher(&server, &cache).input_path(2022, 25).exists());
+        fs::remove_dir_all(cache).unwrap();
+    }
+
+    #[test]
+    fn input_when_no_session_returns_missing_session() {
+        let cache = cache_dir("no-session");
+        let fetcher = Fetcher::new(UreqClient::new(), "http://127.0.0.1:9", None, cache);
+
+        let result = fetcher.input(2022, 1).unwrap_err();
+
+        assert!(matches!(result, FetchError::MissingSession));
+    }
+
+    #[test]
+    fn remaining_wait_spaces_requests() {
+        let now = SystemTime::now();
+        let interval = Duration::from_secs(5);
+
+        assert_eq!(remaining_wait(None, now, interval), Duration::ZERO);
+        assert_eq!(
+            remaining_wait(Some(now - Duration::from_secs(2)), now, interval),
+            Duration::from_secs(3)
+        );
+        assert_eq!(
+            remaining_wait(Some(now - Duration::from_secs(9)), now, interval),
+            Duration::ZERO
+        );
+    }
+}
@@ -0,0 +1,307 @@
+use std::io::{self, Write};
+
+use crate::rng::Rng;
+
+/// A generator of valid puzzle inputs of any size for one day.
+pub struct Generator {
+    pub year: u16,
+    pub day: u8,
+    /// What `size` counts, like "elves" or "moves".
+    pub unit: &'static str,
+    pub generate: fn(&mut Rng, usize, &mut dyn Write) -> io::Result<()>,
+}
+
+pub const GENERATORS: &[Generator] = &[
+    Generator {
+        year: 2022,
+        day: 1,
+        unit: "elves",
+        generate: calories,
+    },
+    Generator {
+        year: 2022,
+        day: 2,
+        unit: "rounds",
+        generate: strategy_guide,
+    },
+    Generator {
+        year: 2022,
+        day: 3,
+        unit: "groups of three rucksacks",
+        generate: rucksacks,
+    },
+    Generator {
+        year: 2022,
+        day: 4,
+        unit: "pairs",
+        generate: section_pairs,
+    },
+    Generator {
+        year: 2022,
+        day: 5,
+        unit: "moves",
+        generate: crane_procedure,
+    },
+    Generator {
+        year: 2022,
+        day: 7,
+        unit: "directories",
+        generate: terminal_log,
+    },
+    Generator {
+        year: 2022,
+        day: 9,
+        unit: "motions",
+        generate: rope_motions,
+    },
+];
+
+pub fn find(year: u16, day: u8) -> Option<&'static Generator> {
+    GENERATORS
+        .iter()
+        .find(|generator| generator.year == year && generator.day == day)
+}
+
+/// Writes the input `generator` produces for `seed`, the same seed always giving the same input.
+pub fn generate(
+    generator: &Generator,
+    seed: u64,
+    size: usize,
+    out: &mut dyn Write,
+) -> io::Result<()> {
+    (generator.generate)(&mut Rng::new(seed), size, out)
+}
+
+/// Groups of calorie counts separated by blank lines.
+fn calories(rng: &mut Rng, elves: usize, out: &mut dyn Write) -> io::Result<()> {
+    for elf in 0..elves {
+        if elf > 0 {
+            writeln!(out)?;
+        }
+        for _ in 0..rng.range(1..=6) {
+            writeln!(out, "{}", rng.range(1000..=60000))?;
+        }
+    }
+    Ok(())
+}
+
+/// Lines like `A Y`, the opponent's shape followed by the second column.
+fn strategy_guide(rng: &mut Rng, rounds: usize, out: &mut dyn Write) -> io::Result<()> {
+    for _ in 0..rounds {
+        let opponent = rng.pick(&['A', 'B', 'C']);
+        let player = rng.pick(&['X', 'Y', 'Z']);
+        writeln!(out, "{opponent} {player}")?;
+    }
+    Ok(())
+}
+
+/// Rucksacks whose compartments share exactly one item type, in groups of three that share
+/// exactly one badge.
+fn rucksacks(rng: &mut Rng, groups: usize, out: &mut dyn Write) -> io::Result<()> {
+    let mut items: Vec<char> = ('a'..='z').chain('A'..='Z').collect();
+    for _ in 0..groups {
+        rng.shuffle(&mut items);
+        let (badge, rest) = items.split_first().expect("there are 52 item types");
+
+        // Each elf draws from its own 17 item types, so only the badge is common to all three.
+        for own in rest.chunks(17) {
+            let (duplicate, rest) = own.split_first().expect("17 item types per elf");
+            let (first_pool, second_pool) = rest.split_at(rest.len() / 2);
+
+            let size = rng.range(2..=12);
+            let badge_in_first = rng.below(2) == 0;
+            let mut compartments = [vec![*duplicate], vec![*duplicate]];
+            compartments[usize::from(!badge_in_first)].push(*badge);
+            for (compartment, pool) in compartments.iter_mut().zip([first_pool, second_pool]) {
+                while compartment.len() < size {
+                    compartment.push(*rng.pick(pool));
+                }
+                rng.shuffle(compartment);
+            }
+
+            let line: String = compartments.concat().into_iter().collect();
+            writeln!(out, "{line}")?;
+        }
+    }
+    Ok(())
+}
+
+/// Lines like `2-4,6-8`, two section ranges.
+fn section_pairs(rng: &mut Rng, pairs: usize, out: &mut dyn Write) -> io::Result<()> {
+    for _ in 0..pairs {
+        let mut range = || {
+            let start = rng.range(1..=99);
+            (start, rng.range(start..=99))
+        };
+        let ((first_start, first_end), (second_start, second_end)) = (range(), range());
+        writeln!(out, "{first_start}-{first_end},{second_start}-{second_end}")?;
+    }
+    Ok(())
+}
+
+/// A crate diagram of nine stacks followed by moves that never take more crates than a stack
+/// holds.
+fn crane_procedure(rng: &mut Rng, moves: usize, out: &mut dyn Write) -> io::Result<()> {
+    const STACKS: usize = 9;
+
+    let mut stacks: Vec<Vec<char>> = (0..STACKS)
+        .map(|_| {
+            (0..rng.range(1..=8))
+                .map(|_| char::from(b'A' + rng.below(26) as u8))
+                .collect()
+        })
+        .collect();
+    let height = stacks.iter().map(Vec::len).max().unwrap_or_default();
+    for row in (0..height).rev() {
+        let cells: Vec<String> = stacks
+            .iter()
+            .map(|stack| match stack.get(row) {
+                Some(label) => format!("[{label}]"),
+                None => String::from("   "),
+            })
+            .collect();
+        writeln!(out, "{}", cells.join(" "))?;
+    }
+    let numbers: Vec<String> = (1..=STACKS).map(|number| format!(" {number} ")).collect();
+    writeln!(out, "{}", numbers.join(" "))?;
+    writeln!(out)?;
+
+    for _ in 0..moves {
+        let filled: Vec<usize> = (0..STACKS).filter(|&i| !stacks[i].is_empty()).collect();
+        let from = *rng.pick(&filled);
+        let to = (from + rng.range(1..=STACKS - 1)) % STACKS;
+        let amount = rng.range(1..=stacks[from].len().min(6));
+
+        let start = stacks[from].len() - amount;
+        let moved: Vec<char> = stacks[from].drain(start..).collect();
+        stacks[to].extend(moved);
+        writeln!(out, "move {amount} from {} to {}", from + 1, to + 1)?;
+    }
+    Ok(())
+}
+
+/// A `$ cd` and `$ ls` session that lists every directory once, walking the tree depth first.
+///
+/// Directory names are unique across the whole tree.
+fn terminal_log(rng: &mut Rng, directories: usize, out: &mut dyn Write) -> io::Result<()> {
+    // Index 0 is the root; every other directory hangs below a random earlier one.
+    let mut children: Vec<Vec<usize>> = vec![Vec::new(); directories + 1];
+    for directory in 1..=directories {
+        children[rng.below(directory)].push(directory);
+    }
+
+    writeln!(out, "$ cd /")?;
+    let mut stack = vec![(0, 0)];
+    while let Some((directory, next_child)) = stack.pop() {
+        if next_child == 0 {
+            writeln!(out, "$ ls")?;
+            let mut entries: Vec<String> = children[directory]
+                .iter()
+                .map(|&child| format!("dir {}", directory_name(child)))
+                .collect();
+            for file in 0..rng.range(0..=3) {
+                let extension = rng.pick(&["", ".txt", ".dat", ".log"]);
+                entries.push(format!("{} f{file}{extension}", rng.range(1..=300_000)));
+            }
+            rng.shuffle(&mut entries);
+            for entry in entries {
+                writeln!(out, "{entry}")?;
+            }
+        }
+
+        match children[directory].get(next_child) {
+            Some(&child) => {
+                writeln!(out, "$ cd {}", directory_name(child))?;
+                stack.push((directory, next_child + 1));
+                stack.push((child, 0));
+            }
+            None if directory != 0 => writeln!(out, "$ cd ..")?,
+            None => {}
+        }
+    }
+    Ok(())
+}
+
+/// `a` to `z`, then `aa`, `ab` and so on.
+fn directory_name(directory: usize) -> String {
+    let mut name = Vec::new();
+    let mut rest = directory;
+    while rest > 0 {
+        rest -= 1;
+        name.push(b'a' + (rest % 26) as u8);
+        rest /= 26;
+    }
+    name.reverse();
+    String::from_utf8(name).expect("letters are ASCII")
+}
+
+/// Lines like `R 4`, a direction and a number of steps.
+fn rope_motions(rng: &mut Rng, motions: usize, out: &mut dyn Write) -> io::Result<()> {
+    for _ in 0..motions {
+        let direction = rng.pick(&['R', 'L', 'U', 'D']);
+        writeln!(out, "{direction} {}", rng.range(1..=19))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+
+    fn generated(generator: &Generator, seed: u64, size: usize) -> String {
+        let mut out = Vec::new();
+        generate(generator, seed, size, &mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn every_generator_produces_input_its_day_solves() {
+        for generator in GENERATORS {
+            let input = generated(generator, 7, 200);
+
+            for entry in registry::find(generator.year, generator.day, None) {
+                let result = (entry.solve)(&input);
+
+                assert!(
+                    result.is_ok(),
+                    "{} day {} part {}: {}",
+                    entry.year,
+                    entry.day,
+                    entry.part,
+                    result.unwrap_err()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn generate_is_deterministic_per_seed() {
+        let generator = find(2022, 9).unwrap();
+
+        assert_eq!(generated(generator, 3, 50), generated(generator, 3, 50));
+        assert_ne!(generated(generator, 3, 50), generated(generator, 4, 50));
+    }
+
+    #[test]
+    fn calories_writes_requested_number_of_elves() {
+        let input = generated(find(2022, 1).unwrap(), 1, 25);
+
+        assert_eq!(input.split("\n\n").count(), 25);
+    }
+
+    #[test]
+    fn terminal_log_lists_every_directory_once() {
+        let input = generated(find(2022, 7).unwrap(), 1, 100);
+
+        assert_eq!(input.lines().filter(|line| *line == "$ ls").count(), 101);
+        assert!(input.starts_with("$ cd /\n$ ls\n"));
+    }
+
+    #[test]
+    fn directory_name_counts_in_letters() {
+        assert_eq!(directory_name(1), "a");
+        assert_eq!(directory_name(26), "z");
+        assert_eq!(directory_name(27), "aa");
+    }
+}
@@ -0,0 +1,536 @@
+mod answers;
+mod bench;
+mod client;
+mod config;
+mod fetch;
+mod generate;
+mod registry;
+mod rng;
+mod run_all;
+mod runner;
+mod scaffold;
+#[cfg(test)]
+mod stub_server;
+mod submit;
+mod verify;
+
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::thread;
+
+use aoc_common::{Error, InputArgs, InputSource};
+use clap::{Args, Parser, Subcommand};
+
+use crate::answers::Answers;
+use crate::bench::Baseline;
+use crate::client::UreqClient;
+use crate::config::Config;
+use crate::fetch::Fetcher;
+use crate::registry::Entry;
+
+#[derive(Parser)]
+#[command(
+    name = "aoc",
+    about = "Runs the Advent of Code solutions in this repository"
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run one day, one part of a day, or every registered day
+    Run(RunArgs),
+    /// Check every registered day against the recorded answers
+    Verify(VerifyArgs),
+    /// Time parsing and solving of each part and compare with a saved baseline
+    Bench(BenchArgs),
+    /// Download a day's puzzle input into its input.txt
+    Fetch(FetchArgs),
+    /// Submit the answer the runner computes for one part
+    Submit(SubmitArgs),
+    /// Generate the crate for a new day and register it with the runner
+    New(NewArgs),
+    /// Write a random but valid puzzle input of any size, reproducible from its seed
+    Gen(GenArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    #[arg(long, required_unless_present = "all")]
+    year: Option<u16>,
+
+    #[arg(long, required_unless_present = "all")]
+    day: Option<u8>,
+
+    #[arg(long)]
+    part: Option<u8>,
+
+    #[command(flatten)]
+    input: InputArgs,
+
+    /// Run every registered day in parallel and print a summary table
+    #[arg(long, conflicts_with_all = ["year", "day", "part", "input"])]
+    all: bool,
+
+    /// Number of threads for --all, defaults to the available parallelism
+    #[arg(long, requires = "all", value_parser = clap::value_parser!(u16).range(1..))]
+    jobs: Option<u16>,
+}
+
+#[derive(Args)]
+struct VerifyArgs {
+    /// Answers manifest to check against, defaults to answers.txt at the workspace root
+    #[arg(long)]
+    answers: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    /// Only benchmark this year, every registered year when omitted
+    #[arg(long)]
+    year: Option<u16>,
+
+    #[arg(long, requires = "year")]
+    day: Option<u8>,
+
+    #[arg(long, requires = "day")]
+    part: Option<u8>,
+
+    /// Number of timed runs per part
+    #[arg(long, default_value_t = 50)]
+    iterations: usize,
+
+    /// Baseline file, defaults to bench-baseline.txt at the workspace root
+    #[arg(long)]
+    baseline: Option<PathBuf>,
+
+    /// Overwrite the baseline with the medians of this run
+    #[arg(long)]
+    save_baseline: bool,
+
+    /// Slowdown of the median, in percent, above which a regression is flagged
+    #[arg(long, default_value_t = 10.0)]
+    threshold: f64,
+}
+
+#[derive(Args)]
+struct FetchArgs {
+    #[arg(long)]
+    year: u16,
+
+    #[arg(long)]
+    day: u8,
+
+    /// Write the input here instead of the input.txt of every registered crate of the day
+    #[arg(long)]
+    output: Option<PathBuf>,
+
+    /// Site to download from, overrides the configured base URL
+    #[arg(long)]
+    base_url: Option<String>,
+}
+
+#[derive(Args)]
+struct SubmitArgs {
+    /// Defaults to the latest registered year
+    #[arg(long)]
+    year: Option<u16>,
+
+    #[arg(long)]
+    day: u8,
+
+    #[arg(long)]
+    part: u8,
+
+    /// Site to submit to, overrides the configured base URL
+    #[arg(long)]
+    base_url: Option<String>,
+}
+
+#[derive(Args)]
+struct NewArgs {
+    #[arg(long)]
+    year: u16,
+
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+}
+
+#[derive(Args)]
+struct GenArgs {
+    #[arg(long)]
+    year: u16,
+
+    #[arg(long)]
+    day: u8,
+
+    /// Number of records to generate, what a record is depends on the day
+    #[arg(long, default_value_t = 1000)]
+    size: usize,
+
+    #[arg(long, default_value_t = 1)]
+    seed: u64,
+
+    /// Write the input here instead of to stdout
+    #[arg(long)]
+    output: Option<PathBuf>,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Run(args) => run(args),
+        Command::Verify(args) => verify(args),
+        Command::Bench(args) => bench(args),
+        Command::Fetch(args) => fetch(args),
+        Command::Submit(args) => submit(args),
+        Command::New(args) => new_day(args),
+        Command::Gen(args) => generate_input(args),
+    }
+}
+
+fn run(args: RunArgs) -> ExitCode {
+    if args.all {
+        return run_all(&args);
+    }
+
+    let year = args.year.expect("year is required without --all");
+    let day = args.day.expect("day is required without --all");
+
+    let entries = registry::find(year, day, args.part);
+    if entries.is_empty() {
+        eprintln!("No solution registered for {year} day {day:02}");
+        return ExitCode::FAILURE;
+    }
+
+    run_entries(entries.into_iter(), &args.input)
+}
+
+fn run_entries<'a>(entries: impl Iterator<Item = &'a Entry>, input: &InputArgs) -> ExitCode {
+    // Standard input can only be read once, so it is shared by every part.
+    let stdin = match input.source_in(Path::new(".")) {
+        InputSource::Stdin => match InputSource::Stdin.load() {
+            Ok(text) => Some(text),
+            Err(error) => {
+                eprintln!("error: {error}");
+                return ExitCode::FAILURE;
+            }
+        },
+        InputSource::File(_) => None,
+    };
+
+    let mut exit_code = ExitCode::SUCCESS;
+    for entry in entries {
+        let result = match &stdin {
+            Some(text) => runner::run(entry, text),
+            None => runner::load(entry, input).and_then(|text| runner::run(entry, &text)),
+        };
+        if let Err(error) = result {
+            eprintln!(
+                "{} day {:02} part {} failed: {error}",
+                entry.year, entry.day, entry.part
+            );
+            exit_code = ExitCode::FAILURE;
+        }
+    }
+    exit_code
+}
+
+fn run_all(args: &RunArgs) -> ExitCode {
+    let jobs = args.jobs.map(usize::from).unwrap_or_else(|| {
+        thread::available_parallelism()
+            .map(usize::from)
+            .unwrap_or(1)
+    });
+    let entries: Vec<&Entry> = registry::ENTRIES.iter().collect();
+
+    let outcomes = run_all::run_all(&entries, jobs, |entry| runner::load(entry, &args.input));
+    run_all::print_table(&outcomes);
+
+    let failures = outcomes
+        .iter()
+        .filter(|outcome| outcome.status.is_failure())
+        .count();
+    if failures > 0 {
+        eprintln!("{failures} of {} parts failed", outcomes.len());
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
+
+fn verify(args: VerifyArgs) -> ExitCode {
+    let root = runner::workspace_root();
+    let path = args.answers.unwrap_or_else(|| root.join("answers.txt"));
+    let answers = match Answers::load(&path) {
+        Ok(answers) => answers,
+        Err(error) => {
+            eprintln!("error: {error}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let outcomes = verify::verify(registry::ENTRIES, &answers, root);
+    verify::print_table(&outcomes);
+
+    let failures = outcomes
+        .iter()
+        .filter(|outcome| outcome.status.is_failure())
+        .count();
+    if failures > 0 {
+        eprintln!("{failures} of {} checks failed", outcomes.len());
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
+
+fn bench(args: BenchArgs) -> ExitCode {
+    let baseline_path = args
+        .baseline
+        .unwrap_or_else(|| runner::workspace_root().join("bench-baseline.txt"));
+    let mut baseline = if baseline_path.exists() {
+        match Baseline::load(&baseline_path) {
+            Ok(baseline) => baseline,
+            Err(error) => {
+                eprintln!("error: {error}");
+                return ExitCode::FAILURE;
+            }
+        }
+    } else {
+        Baseline::default()
+    };
+
+    let entries = registry::ENTRIES.iter().filter(|entry| {
+        args.year.is_none_or(|year| year == entry.year)
+            && args.day.is_none_or(|day| day == entry.day)
+            && args.part.is_none_or(|part| part == entry.part)
+    });
+
+    let mut exit_code = ExitCode::SUCCESS;
+    let mut measurements = Vec::new();
+    for entry in entries {
+        let result = aoc_common::read_input(runner::default_input_path(entry))
+            .map_err(Error::from)
+            .and_then(|input| bench::bench(entry, &input, args.iterations));
+        match result {
+            Ok(measured) => measurements.extend(measured),
+            Err(error) => {
+                eprintln!(
+                    "{} day {:02} part {} failed: {error}",
+                    entry.year, entry.day, entry.part
+                );
+                exit_code = ExitCode::FAILURE;
+            }
+        }
+    }
+
+    let regressions = bench::print_table(&measurements, &baseline, args.threshold);
+    if regressions > 0 {
+        eprintln!(
+            "{regressions} phases are more than {}% slower than the baseline",
+            args.threshold
+        );
+        exit_code = ExitCode::FAILURE;
+    }
+
+    if args.save_baseline {
+        baseline.record(&measurements);
+        if let Err(error) = baseline.save(&baseline_path) {
+            eprintln!("error: could not save {}: {error}", baseline_path.display());
+            return ExitCode::FAILURE;
+        }
+        println!("Saved baseline to {}", baseline_path.display());
+    }
+
+    exit_code
+}
+
+fn fetch(args: FetchArgs) -> ExitCode {
+    let config = match Config::load() {
+        Ok(config) => config,
+        Err(error) => {
+            eprintln!("error: {error}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let base_url = args.base_url.as_deref().unwrap_or(&config.base_url);
+    let fetcher = Fetcher::new(
+        UreqClient::new(),
+        base_url,
+        config.session,
+        config.cache_dir,
+    );
+
+    let outputs = match args.output {
+        Some(output) => vec![output],
+        None => {
+            let mut dirs: Vec<&str> = registry::find(args.year, args.day, None)
+                .iter()
+                .map(|entry| entry.dir)
+                .collect();
+            dirs.dedup();
+            dirs.iter()
+                .map(|dir| runner::workspace_root().join(dir).join("input.txt"))
+                .collect()
+        }
+    };
+    if outputs.is_empty() {
+        eprintln!(
+            "No crate registered for {} day {:02}, pass --output",
+            args.year, args.day
+        );
+        return ExitCode::FAILURE;
+    }
+
+    let input = match fetcher.input(args.year, args.day) {
+        Ok(input) => input,
+        Err(error) => {
+            eprintln!("error: {error}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut exit_code = ExitCode::SUCCESS;
+    for output in outputs {
+        if output.exists() {
+            println!("Kept existing {}", output.display());
+            continue;
+        }
+        match std::fs::write(&output, &input) {
+            Ok(()) => println!("Wrote {}", output.display()),
+            Err(error) => {
+                eprintln!("error: could not write {}: {error}", output.display());
+                exit_code = ExitCode::FAILURE;
+            }
+        }
+    }
+    exit_code
+}
+
+fn submit(args: SubmitArgs) -> ExitCode {
+    let year = args.year.unwrap_or_else(|| {
+        registry::ENTRIES
+            .iter()
+            .map(|entry| entry.year)
+            .max()
+            .expect("at least one day is registered")
+    });
+    let Some(entry) = registry::find(year, args.day, Some(args.part)).pop() else {
+        eprintln!(
+            "No solution registered for {year} day {:02} part {}",
+            args.day, args.part
+        );
+        return ExitCode::FAILURE;
+    };
+
+    let answer =
+        match runner::load(entry, &InputArgs::default()).and_then(|input| (entry.solve)(&input)) {
+            Ok(answer) => answer,
+            Err(error) => {
+                eprintln!("error: {error}");
+                return ExitCode::FAILURE;
+            }
+        };
+
+    let config = match Config::load() {
+        Ok(config) => config,
+        Err(error) => {
+            eprintln!("error: {error}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let base_url = args.base_url.as_deref().unwrap_or(&config.base_url);
+    let fetcher = Fetcher::new(
+        UreqClient::new(),
+        base_url,
+        config.session,
+        config.cache_dir,
+    );
+
+    match submit::submit(&fetcher, year, args.day, args.part, &answer) {
+        Ok(submission) => {
+            if submission.sent {
+                println!("{answer}: {}", submission.verdict);
+            } else {
+                println!("{answer}: {} (not sent)", submission.verdict);
+            }
+            if submission.verdict.is_success() {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::FAILURE
+            }
+        }
+        Err(error) => {
+            eprintln!("error: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn new_day(args: NewArgs) -> ExitCode {
+    let day = scaffold::Day {
+        year: args.year,
+        day: args.day,
+    };
+    match scaffold::create(runner::workspace_root(), &day) {
+        Ok(dir) => {
+            println!("Created {} in {}", day.package(), dir.display());
+            println!(
+                "Add the sample to input_test.txt, then run: cargo run -p aoc -- run --year {} --day {} --test",
+                day.year, day.day
+            );
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("error: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn generate_input(args: GenArgs) -> ExitCode {
+    let Some(generator) = generate::find(args.year, args.day) else {
+        eprintln!(
+            "No generator for {} day {:02}, days with one: {}",
+            args.year,
+            args.day,
+            generate::GENERATORS
+                .iter()
+                .map(|generator| format!("{} day {:02}", generator.year, generator.day))
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+        return ExitCode::FAILURE;
+    };
+
+    let out: io::Result<Box<dyn Write>> = match &args.output {
+        Some(path) => File::create(path).map(|file| Box::new(file) as Box<dyn Write>),
+        None => Ok(Box::new(io::stdout().lock())),
+    };
+    let result = out.and_then(|out| {
+        let mut out = BufWriter::new(out);
+        generate::generate(generator, args.seed, args.size, &mut out)?;
+        out.flush()
+    });
+    match result {
+        Ok(()) => {
+            if let Some(path) = &args.output {
+                println!(
+                    "Wrote {} {} to {}",
+                    args.size,
+                    generator.unit,
+                    path.display()
+                );
+            }
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("error: could not write the input: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
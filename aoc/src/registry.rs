@@ -0,0 +1,156 @@
+use aoc_common::{solve_part1, solve_part2, time_part1, time_part2, Error, PhaseTimings};
+
+pub struct Entry {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub dir: &'static str,
+    pub solve: fn(&str) -> Result<String, Error>,
+    pub time: fn(&str) -> Result<PhaseTimings, Error>,
+}
+
+pub const ENTRIES: &[Entry] = &[
+    Entry {
+        year: 2022,
+        day: 1,
+        part: 1,
+        dir: "2022/day01/rust",
+        solve: solve_part1::<day01::Day01>,
+        time: time_part1::<day01::Day01>,
+    },
+    Entry {
+        year: 2022,
+        day: 1,
+        part: 2,
+        dir: "2022/day01/rust",
+        solve: solve_part2::<day01::Day01>,
+        time: time_part2::<day01::Day01>,
+    },
+    Entry {
+        year: 2022,
+        day: 2,
+        part: 1,
+        dir: "2022/day02/part1",
+        solve: solve_part1::<day02_part1::Day02>,
+        time: time_part1::<day02_part1::Day02>,
+    },
+    Entry {
+        year: 2022,
+        day: 2,
+        part: 2,
+        dir: "2022/day02/part2",
+        solve: solve_part2::<day02_part2::Day02>,
+        time: time_part2::<day02_part2::Day02>,
+    },
+    Entry {
+        year: 2022,
+        day: 3,
+        part: 1,
+        dir: "2022/day03",
+        solve: solve_part1::<day03::Day03>,
+        time: time_part1::<day03::Day03>,
+    },
+    Entry {
+        year: 2022,
+        day: 3,
+        part: 2,
+        dir: "2022/day03",
+        solve: solve_part2::<day03::Day03>,
+        time: time_part2::<day03::Day03>,
+    },
+    Entry {
+        year: 2022,
+        day: 4,
+        part: 1,
+        dir: "2022/day04",
+        solve: solve_part1::<day04::Day04>,
+        time: time_part1::<day04::Day04>,
+    },
+    Entry {
+        year: 2022,
+        day: 4,
+        part: 2,
+        dir: "2022/day04",
+        solve: solve_part2::<day04::Day04>,
+        time: time_part2::<day04::Day04>,
+    },
+    Entry {
+        year: 2022,
+        day: 5,
+        part: 1,
+        dir: "2022/day05/part1",
+        solve: solve_part1::<day05_part1::Day05>,
+        time: time_part1::<day05_part1::Day05>,
+    },
+    Entry {
+        year: 2022,
+        day: 5,
+        part: 2,
+        dir: "2022/day05/part2",
+        solve: solve_part2::<day05_part2::Day05>,
+        time: time_part2::<day05_part2::Day05>,
+    },
+    Entry {
+        year: 2022,
+        day: 7,
+        part: 1,
+        dir: "2022/day07/part1",
+        solve: solve_part1::<day07_part1::Day07>,
+        time: time_part1::<day07_part1::Day07>,
+    },
+    Entry {
+        year: 2022,
+        day: 9,
+        part: 1,
+        dir: "2022/day09/part1",
+        solve: solve_part1::<day09_part1::Day09>,
+        time: time_part1::<day09_part1::Day09>,
+    },
+];
+
+pub fn find(year: u16, day: u8, part: Option<u8>) -> Vec<&'static Entry> {
+    ENTRIES
+        .iter()
+        .filter(|entry| entry.year == year && entry.day == day)
+        .filter(|entry| part.is_none() || part == Some(entry.part))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn entries_are_unique() {
+        for (i, entry) in ENTRIES.iter().enumerate() {
+            let duplicates = ENTRIES[i + 1..]
+                .iter()
+                .filter(|other| {
+                    other.year == entry.year && other.day == entry.day && other.part == entry.part
+                })
+                .count();
+
+            assert_eq!(duplicates, 0);
+        }
+    }
+
+    #[test]
+    fn find_when_part_given_returns_single_entry() {
+        let result = find(2022, 5, Some(2));
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].dir, "2022/day05/part2");
+    }
+
+    #[test]
+    fn find_when_no_part_returns_all_parts() {
+        let result = find(2022, 1, None);
+
+        assert_eq!(result.len(), 2);
+    }
+
+    #[test]
+    fn find_when_unknown_day_returns_empty() {
+        assert!(find(2022, 25, None).is_empty());
+    }
+}
@@ -0,0 +1,213 @@
+use std::any::Any;
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use aoc_common::Error;
+
+use crate::registry::Entry;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Ok,
+    /// Loading or solving the input returned an error.
+    Failed(String),
+    /// The solution panicked, the message is the panic payload when it is a string.
+    Panicked(String),
+}
+
+impl Status {
+    pub fn is_failure(&self) -> bool {
+        !matches!(self, Status::Ok)
+    }
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Ok => write!(f, "ok"),
+            Status::Failed(error) => write!(f, "FAILED: {error}"),
+            Status::Panicked(message) => write!(f, "PANICKED: {message}"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Outcome {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<String>,
+    /// Time spent parsing and solving, without loading the input.
+    pub elapsed: Duration,
+    pub status: Status,
+}
+
+/// Runs every entry on a pool of `jobs` threads, with the input `load` returns for it.
+///
+/// A failing or panicking entry only affects its own outcome. Outcomes are returned in the
+/// order of `entries`.
+pub fn run_all(
+    entries: &[&Entry],
+    jobs: usize,
+    load: impl Fn(&Entry) -> Result<String, Error> + Sync,
+) -> Vec<Outcome> {
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, entries.len().max(1)) {
+            let sender = sender.clone();
+            let (next, load) = (&next, &load);
+            scope.spawn(move || loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                let Some(entry) = entries.get(index) else {
+                    break;
+                };
+                let _ = sender.send((index, run_one(entry, load)));
+            });
+        }
+    });
+    drop(sender);
+
+    let mut outcomes: Vec<(usize, Outcome)> = receiver.into_iter().collect();
+    outcomes.sort_by_key(|&(index, _)| index);
+    outcomes.into_iter().map(|(_, outcome)| outcome).collect()
+}
+
+fn run_one(entry: &Entry, load: &impl Fn(&Entry) -> Result<String, Error>) -> Outcome {
+    let mut elapsed = Duration::ZERO;
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        let input = load(entry)?;
+        let start = Instant::now();
+        let answer = (entry.solve)(&input);
+        elapsed = start.elapsed();
+        answer
+    }));
+
+    let (answer, status) = match result {
+        Ok(Ok(answer)) => (Some(answer), Status::Ok),
+        Ok(Err(error)) => (None, Status::Failed(error.to_string())),
+        Err(payload) => (None, Status::Panicked(panic_message(payload.as_ref()))),
+    };
+    Outcome {
+        year: entry.year,
+        day: entry.day,
+        part: entry.part,
+        answer,
+        elapsed,
+        status,
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        String::from(*message)
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        String::from("non-string panic payload")
+    }
+}
+
+pub fn print_table(outcomes: &[Outcome]) {
+    let answer_width = outcomes
+        .iter()
+        .filter_map(|outcome| outcome.answer.as_ref())
+        .map(|answer| answer.len())
+        .chain(["answer".len()])
+        .max()
+        .unwrap_or_default();
+
+    println!(
+        "{:<4}  {:<3}  {:<4}  {:<answer_width$}  {:>10}  status",
+        "year", "day", "part", "answer", "time"
+    );
+    for outcome in outcomes {
+        println!(
+            "{:<4}  {:<3}  {:<4}  {:<answer_width$}  {:>10}  {}",
+            outcome.year,
+            format!("{:02}", outcome.day),
+            outcome.part,
+            outcome.answer.as_deref().unwrap_or("-"),
+            format!("{:.1?}", outcome.elapsed),
+            outcome.status
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::ParseError;
+
+    use super::*;
+
+    fn entry(day: u8, solve: fn(&str) -> Result<String, Error>) -> Entry {
+        Entry {
+            year: 2022,
+            day,
+            part: 1,
+            dir: "unused",
+            solve,
+            time: |_| unreachable!("run_all does not time entries"),
+        }
+    }
+
+    fn echo(input: &str) -> Result<String, Error> {
+        Ok(String::from(input))
+    }
+
+    fn fail(_: &str) -> Result<String, Error> {
+        Err(ParseError::missing(0, "a number").into())
+    }
+
+    fn explode(_: &str) -> Result<String, Error> {
+        panic!("no directory named a")
+    }
+
+    #[test]
+    fn run_all_keeps_entry_order() {
+        let entries: Vec<Entry> = (1..=6).map(|day| entry(day, echo)).collect();
+        let entries: Vec<&Entry> = entries.iter().collect();
+
+        let outcomes = run_all(&entries, 3, |entry| Ok(entry.day.to_string()));
+
+        let answers: Vec<_> = outcomes
+            .iter()
+            .map(|outcome| outcome.answer.as_deref().unwrap())
+            .collect();
+        assert_eq!(answers, ["1", "2", "3", "4", "5", "6"]);
+    }
+
+    #[test]
+    fn run_all_isolates_errors_and_panics() {
+        let entries = [entry(1, explode), entry(2, fail), entry(3, echo)];
+        let entries: Vec<&Entry> = entries.iter().collect();
+
+        let outcomes = run_all(&entries, 2, |_| Ok(String::from("x")));
+
+        assert_eq!(
+            outcomes[0].status,
+            Status::Panicked(String::from("no directory named a"))
+        );
+        assert!(matches!(outcomes[1].status, Status::Failed(_)));
+        assert_eq!(outcomes[2].status, Status::Ok);
+        assert_eq!(outcomes[2].answer.as_deref(), Some("x"));
+    }
+
+    #[test]
+    fn run_all_when_load_fails_reports_failure() {
+        let entries = [entry(1, echo)];
+        let entries: Vec<&Entry> = entries.iter().collect();
+
+        let outcomes = run_all(&entries, 4, |_| {
+            Err(ParseError::missing(0, "an input").into())
+        });
+
+        assert!(outcomes[0].status.is_failure());
+        assert_eq!(outcomes[0].answer, None);
+    }
+}
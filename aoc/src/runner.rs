@@ -0,0 +1,40 @@
+use std::path::{Path, PathBuf};
+
+use aoc_common::{read_input, Error, InputArgs};
+
+use crate::registry::Entry;
+
+/// Loads the input chosen on the command line, relative to the entry's day directory.
+///
+/// Solutions parse from a string, so the whole input is read at once, unlike the day binaries,
+/// which read their answers line by line.
+pub fn load(entry: &Entry, input: &InputArgs) -> Result<String, Error> {
+    let dir = workspace_root().join(entry.dir);
+    let text = input.source_in(&dir).load()?;
+    Ok(text)
+}
+
+pub fn run(entry: &Entry, input: &str) -> Result<(), Error> {
+    let answer = (entry.solve)(input)?;
+
+    println!(
+        "{} day {:02} part {}: {}",
+        entry.year, entry.day, entry.part, answer
+    );
+    Ok(())
+}
+
+pub fn solve(entry: &Entry, path: &Path) -> Result<String, Error> {
+    let input = read_input(path)?;
+    (entry.solve)(&input)
+}
+
+pub fn default_input_path(entry: &Entry) -> PathBuf {
+    workspace_root().join(entry.dir).join("input.txt")
+}
+
+pub fn workspace_root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("aoc crate lives inside the workspace")
+}
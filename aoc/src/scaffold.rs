@@ -0,0 +1,328 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+const CARGO_TEMPLATE: &str = include_str!("../templates/Cargo.toml.template");
+const LIB_TEMPLATE: &str = include_str!("../templates/lib.rs.template");
+const MAIN_TEMPLATE: &str = include_str!("../templates/main.rs.template");
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    AlreadyExists(PathBuf),
+    /// A file that gets edited to register the day does not look as expected.
+    Unrecognised {
+        path: PathBuf,
+        expected: String,
+    },
+    Io {
+        path: PathBuf,
+        source: io::Error,
+    },
+}
+
+impl fmt::Display for ScaffoldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScaffoldError::AlreadyExists(path) => write!(f, "{} already exists", path.display()),
+            ScaffoldError::Unrecognised { path, expected } => {
+                write!(f, "could not find {expected} in {}", path.display())
+            }
+            ScaffoldError::Io { path, source } => {
+                write!(f, "could not write {}: {source}", path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for ScaffoldError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ScaffoldError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// Names used for the crate of one day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+}
+
+impl Day {
+    /// Directory of the crate relative to the workspace root, like `2022/day10`.
+    pub fn dir(&self) -> String {
+        format!("{}/day{:02}", self.year, self.day)
+    }
+
+    /// Package name, which includes the year so it stays unique across years.
+    pub fn package(&self) -> String {
+        format!("aoc-{}-day{:02}", self.year, self.day)
+    }
+
+    pub fn crate_name(&self) -> String {
+        self.package().replace('-', "_")
+    }
+
+    /// Name of the type implementing `Solution`.
+    pub fn type_name(&self) -> String {
+        format!("Day{:02}", self.day)
+    }
+
+    fn render(&self, template: &str) -> String {
+        template
+            .replace("{package}", &self.package())
+            .replace("{crate_name}", &self.crate_name())
+            .replace("{name}", &self.type_name())
+            .replace("{year}", &self.year.to_string())
+            .replace("{day}", &self.day.to_string())
+    }
+}
+
+/// Generates the crate for `day` under `root` and registers it with the workspace and runner.
+///
+/// Returns the directory of the new crate.
+pub fn create(root: &Path, day: &Day) -> Result<PathBuf, ScaffoldError> {
+    let dir = root.join(day.dir());
+    if dir.exists() {
+        return Err(ScaffoldError::AlreadyExists(dir));
+    }
+
+    // Edit the existing files first, so nothing is written if one of them is not recognised.
+    let workspace_manifest = root.join("Cargo.toml");
+    let runner_manifest = root.join("aoc/Cargo.toml");
+    let registry = root.join("aoc/src/registry.rs");
+    let edits = [
+        (
+            add_member(&read(&workspace_manifest)?, &day.dir()),
+            workspace_manifest,
+            "the workspace members list",
+        ),
+        (
+            add_dependency(&read(&runner_manifest)?, day),
+            runner_manifest,
+            "the [dependencies] section",
+        ),
+        (
+            register(&read(&registry)?, day),
+            registry,
+            "the ENTRIES list",
+        ),
+    ];
+    let mut edited = Vec::new();
+    for (text, path, expected) in edits {
+        let Some(text) = text else {
+            return Err(ScaffoldError::Unrecognised {
+                path,
+                expected: String::from(expected),
+            });
+        };
+        edited.push((path, text));
+    }
+
+    write(&dir.join("Cargo.toml"), &day.render(CARGO_TEMPLATE))?;
+    write(&dir.join("src/lib.rs"), &day.render(LIB_TEMPLATE))?;
+    write(&dir.join("src/main.rs"), &day.render(MAIN_TEMPLATE))?;
+    write(&dir.join("input_test.txt"), "")?;
+    for (path, text) in edited {
+        write(&path, &text)?;
+    }
+
+    Ok(dir)
+}
+
+fn read(path: &Path) -> Result<String, ScaffoldError> {
+    fs::read_to_string(path).map_err(|source| ScaffoldError::Io {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+fn write(path: &Path, text: &str) -> Result<(), ScaffoldError> {
+    let io_error = |source| ScaffoldError::Io {
+        path: path.to_path_buf(),
+        source,
+    };
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(io_error)?;
+    }
+    fs::write(path, text).map_err(io_error)
+}
+
+/// Appends `member` to the `members` list of the workspace manifest.
+fn add_member(manifest: &str, member: &str) -> Option<String> {
+    let start = manifest.find("members = [")?;
+    let end = start + manifest[start..].find("\n]")?;
+    let mut edited = String::from(&manifest[..end]);
+    edited.push_str(&format!("\n    \"{member}\","));
+    edited.push_str(&manifest[end..]);
+    Some(edited)
+}
+
+/// Adds the day crate as a path dependency at the end of the `[dependencies]` section.
+fn add_dependency(manifest: &str, day: &Day) -> Option<String> {
+    let start = manifest.find("[dependencies]\n")?;
+    let end = match manifest[start..].find("\n\n") {
+        Some(offset) => start + offset + 1,
+        None => manifest.len(),
+    };
+    let mut edited = String::from(&manifest[..end]);
+    if !edited.ends_with('\n') {
+        edited.push('\n');
+    }
+    edited.push_str(&format!(
+        "{} = {{ path = \"../{}\" }}\n",
+        day.package(),
+        day.dir()
+    ));
+    edited.push_str(&manifest[end..]);
+    Some(edited)
+}
+
+/// Adds an entry for both parts of the day at the end of `ENTRIES`.
+fn register(source: &str, day: &Day) -> Option<String> {
+    let start = source.find("pub const ENTRIES: &[Entry] = &[")?;
+    let end = start + source[start..].find("\n];")? + 1;
+
+    let mut edited = String::from(&source[..end]);
+    for part in [1, 2] {
+        edited.push_str(&format!(
+            "    Entry {{
+        year: {year},
+        day: {day},
+        part: {part},
+        dir: \"{dir}\",
+        solve: solve_part{part}::<{krate}::{name}>,
+        time: time_part{part}::<{krate}::{name}>,
+    }},
+",
+            year = day.year,
+            day = day.day,
+            dir = day.dir(),
+            krate = day.crate_name(),
+            name = day.type_name(),
+        ));
+    }
+    edited.push_str(&source[end..]);
+    Some(edited)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+
+    const DAY: Day = Day {
+        year: 2022,
+        day: 10,
+    };
+
+    #[test]
+    fn day_names_include_year() {
+        assert_eq!(DAY.dir(), "2022/day10");
+        assert_eq!(DAY.package(), "aoc-2022-day10");
+        assert_eq!(DAY.crate_name(), "aoc_2022_day10");
+        assert_eq!(DAY.type_name(), "Day10");
+    }
+
+    #[test]
+    fn render_fills_main_template() {
+        let main = DAY.render(MAIN_TEMPLATE);
+
+        assert!(main.contains("use aoc_2022_day10::Day10;"));
+        assert!(main.contains("println!(\"Part 1: {}\", Day10::part1(&parsed)?);"));
+        assert!(main.contains("Report::part2::<Day10>(2022, 10, &input)"));
+    }
+
+    #[test]
+    fn add_member_appends_to_list() {
+        let manifest = "[workspace]\nmembers = [\n    \"aoc\",\n]\n\n[workspace.package]\n";
+
+        let result = add_member(manifest, "2022/day10").unwrap();
+
+        assert_eq!(
+            result,
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"2022/day10\",\n]\n\n[workspace.package]\n"
+        );
+    }
+
+    #[test]
+    fn add_dependency_appends_to_section() {
+        let manifest = "[dependencies]\nclap.workspace = true\n\n[lints]\nworkspace = true\n";
+
+        let result = add_dependency(manifest, &DAY).unwrap();
+
+        assert_eq!(
+            result,
+            "[dependencies]\nclap.workspace = true\n\
+             aoc-2022-day10 = { path = \"../2022/day10\" }\n\n[lints]\nworkspace = true\n"
+        );
+    }
+
+    #[test]
+    fn register_adds_both_parts() {
+        let source = "pub const ENTRIES: &[Entry] = &[\n    Entry {},\n];\n\npub fn find() {}\n";
+
+        let result = register(source, &DAY).unwrap();
+
+        assert!(result.contains("solve: solve_part1::<aoc_2022_day10::Day10>,"));
+        assert!(result.contains("time: time_part2::<aoc_2022_day10::Day10>,\n    },\n];\n"));
+    }
+
+    #[test]
+    fn create_when_registry_unrecognised_writes_nothing() {
+        let root = env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        fs::create_dir_all(root.join("aoc/src")).unwrap();
+        fs::write(root.join("Cargo.toml"), "members = [\n]\n").unwrap();
+        fs::write(root.join("aoc/Cargo.toml"), "[dependencies]\n").unwrap();
+        fs::write(root.join("aoc/src/registry.rs"), "// empty\n").unwrap();
+
+        let result = create(&root, &DAY).unwrap_err();
+
+        assert!(matches!(result, ScaffoldError::Unrecognised { .. }));
+        assert!(!root.join("2022").exists());
+        assert_eq!(
+            fs::read_to_string(root.join("Cargo.toml")).unwrap(),
+            "members = [\n]\n"
+        );
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn create_generates_and_registers_day() {
+        let workspace = crate::runner::workspace_root();
+        let root = env::temp_dir().join(format!("aoc-scaffold-create-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        for file in ["Cargo.toml", "aoc/Cargo.toml", "aoc/src/registry.rs"] {
+            write(&root.join(file), &read(&workspace.join(file)).unwrap()).unwrap();
+        }
+
+        let dir = create(&root, &DAY).unwrap();
+
+        assert_eq!(dir, root.join("2022/day10"));
+        let members = read(&root.join("Cargo.toml")).unwrap();
+        assert!(members.contains("    \"2022/day10\",\n]"));
+        let dependencies = read(&root.join("aoc/Cargo.toml")).unwrap();
+        assert!(dependencies.contains("aoc-2022-day10 = { path = \"../2022/day10\" }\n"));
+        let registry = read(&root.join("aoc/src/registry.rs")).unwrap();
+        assert!(registry.contains("solve: solve_part1::<aoc_2022_day10::Day10>,"));
+        assert!(registry.contains("time: time_part2::<aoc_2022_day10::Day10>,"));
+        assert_eq!(
+            read(&dir.join("Cargo.toml")).unwrap(),
+            DAY.render(CARGO_TEMPLATE)
+        );
+        assert_eq!(
+            read(&dir.join("src/lib.rs")).unwrap(),
+            DAY.render(LIB_TEMPLATE)
+        );
+        assert_eq!(
+            read(&dir.join("src/main.rs")).unwrap(),
+            DAY.render(MAIN_TEMPLATE)
+        );
+        assert_eq!(read(&dir.join("input_test.txt")).unwrap(), "");
+        fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,82 @@
+//! A minimal HTTP server on localhost that answers requests with canned responses.
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+
+/// A request as received by the stub server.
+#[derive(Debug)]
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub cookie: Option<String>,
+    pub body: String,
+}
+
+pub struct StubServer {
+    pub base_url: String,
+    requests: Receiver<Request>,
+}
+
+impl StubServer {
+    /// Answers one request per `(status, body)` pair, in order, then stops listening.
+    pub fn start(responses: Vec<(u16, &'static str)>) -> StubServer {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let (sender, requests) = mpsc::channel();
+
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+                let mut parts = request_line.split_whitespace();
+                let method = String::from(parts.next().unwrap_or_default());
+                let path = String::from(parts.next().unwrap_or_default());
+
+                let mut cookie = None;
+                let mut content_length = 0;
+                loop {
+                    let mut header = String::new();
+                    reader.read_line(&mut header).unwrap();
+                    let header = header.trim_end();
+                    if header.is_empty() {
+                        break;
+                    }
+                    let (name, value) = header.split_once(':').unwrap();
+                    match name.to_ascii_lowercase().as_str() {
+                        "cookie" => cookie = Some(String::from(value.trim())),
+                        "content-length" => content_length = value.trim().parse().unwrap(),
+                        _ => {}
+                    }
+                }
+                let mut request_body = vec![0; content_length];
+                reader.read_exact(&mut request_body).unwrap();
+
+                // Recorded before answering so the client always sees its own request.
+                let _ = sender.send(Request {
+                    method,
+                    path,
+                    cookie,
+                    body: String::from_utf8(request_body).unwrap(),
+                });
+
+                let response = format!(
+                    "HTTP/1.1 {status} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                reader.get_mut().write_all(response.as_bytes()).unwrap();
+            }
+        });
+
+        StubServer { base_url, requests }
+    }
+
+    /// Requests received so far.
+    pub fn requests(&self) -> Vec<Request> {
+        self.requests.try_iter().collect()
+    }
+}
@@ -0,0 +1,398 @@
+use std::fmt;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::client::HttpClient;
+use crate::fetch::{FetchError, Fetcher};
+
+/// What the puzzle site said about a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint in which direction.
+    Wrong,
+    AlreadySolved,
+    /// Answered too recently, the site accepts a new answer after the wait.
+    RateLimited(Duration),
+}
+
+impl Verdict {
+    /// Reads the verdict from the HTML of the answer page.
+    pub fn parse(body: &str) -> Option<Verdict> {
+        if body.contains("That's the right answer") {
+            Some(Verdict::Correct)
+        } else if body.contains("That's not the right answer") {
+            if body.contains("your answer is too high") {
+                Some(Verdict::TooHigh)
+            } else if body.contains("your answer is too low") {
+                Some(Verdict::TooLow)
+            } else {
+                Some(Verdict::Wrong)
+            }
+        } else if body.contains("You don't seem to be solving the right level") {
+            Some(Verdict::AlreadySolved)
+        } else if body.contains("You gave an answer too recently") {
+            let wait = left_to_wait(body).unwrap_or(Duration::from_secs(60));
+            Some(Verdict::RateLimited(wait))
+        } else {
+            None
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too-high",
+            Verdict::TooLow => "too-low",
+            Verdict::Wrong => "wrong",
+            Verdict::AlreadySolved => "already-solved",
+            Verdict::RateLimited(_) => "rate-limited",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Verdict> {
+        [
+            Verdict::Correct,
+            Verdict::TooHigh,
+            Verdict::TooLow,
+            Verdict::Wrong,
+            Verdict::AlreadySolved,
+        ]
+        .into_iter()
+        .find(|verdict| verdict.name() == name)
+    }
+
+    pub fn is_success(&self) -> bool {
+        matches!(self, Verdict::Correct | Verdict::AlreadySolved)
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "too high"),
+            Verdict::TooLow => write!(f, "too low"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::AlreadySolved => write!(f, "already solved"),
+            Verdict::RateLimited(wait) => write!(f, "rate limited, wait {}s", wait.as_secs()),
+        }
+    }
+}
+
+/// Parses the `You have 1m 20s left to wait` part of a rate limited answer.
+fn left_to_wait(body: &str) -> Option<Duration> {
+    let start = body.find("You have ")? + "You have ".len();
+    let end = start + body[start..].find(" left to wait")?;
+
+    let mut seconds = 0;
+    for part in body[start..end].split_whitespace() {
+        let (amount, unit) = part.split_at(part.find(|c: char| !c.is_ascii_digit())?);
+        let amount: u64 = amount.parse().ok()?;
+        seconds += match unit {
+            "h" => amount * 3600,
+            "m" => amount * 60,
+            "s" => amount,
+            _ => return None,
+        };
+    }
+    Some(Duration::from_secs(seconds))
+}
+
+/// Parses the `please wait 5 minutes before trying again` part of a wrong answer.
+fn wrong_answer_wait(body: &str) -> Option<Duration> {
+    let start = body.find("wait ")? + "wait ".len();
+    let mut words = body[start..].split_whitespace();
+    let minutes = match words.next()? {
+        "one" => 1,
+        amount => amount.parse().ok()?,
+    };
+    words
+        .next()?
+        .starts_with("minute")
+        .then(|| Duration::from_secs(minutes * 60))
+}
+
+/// Answers already submitted for one part, with the verdict they got.
+pub struct Guesses {
+    path: PathBuf,
+    guesses: Vec<(String, Verdict)>,
+}
+
+impl Guesses {
+    /// Loads the guesses stored at `path`, if any. Unreadable lines are skipped.
+    pub fn load(path: PathBuf) -> Guesses {
+        let guesses = fs::read_to_string(&path)
+            .unwrap_or_default()
+            .lines()
+            .filter_map(|line| {
+                let (answer, verdict) = line.rsplit_once(' ')?;
+                Some((String::from(answer), Verdict::from_name(verdict)?))
+            })
+            .collect();
+        Guesses { path, guesses }
+    }
+
+    pub fn get(&self, answer: &str) -> Option<Verdict> {
+        self.guesses
+            .iter()
+            .find(|(guess, _)| guess == answer)
+            .map(|&(_, verdict)| verdict)
+    }
+
+    pub fn record(&mut self, answer: &str, verdict: Verdict) -> std::io::Result<()> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let mut file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        writeln!(file, "{answer} {}", verdict.name())?;
+        self.guesses.push((String::from(answer), verdict));
+        Ok(())
+    }
+}
+
+/// Result of [`submit`], which only reaches the site for answers not tried before.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Submission {
+    pub verdict: Verdict,
+    /// Whether the answer was sent, or the verdict came from the stored guesses or cooldown.
+    pub sent: bool,
+}
+
+/// Submits `answer` unless it was tried before or the site still asks to wait.
+pub fn submit<C: HttpClient>(
+    fetcher: &Fetcher<C>,
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: &str,
+) -> Result<Submission, FetchError> {
+    let account_dir = fetcher.account_dir();
+    let state_dir = account_dir.join(year.to_string());
+    let mut guesses = Guesses::load(state_dir.join(format!("day{day:02}-part{part}-guesses.txt")));
+    if let Some(verdict) = guesses.get(answer) {
+        return Ok(Submission {
+            verdict,
+            sent: false,
+        });
+    }
+
+    let cooldown = account_dir.join("cooldown-until");
+    let now = SystemTime::now();
+    if let Some(until) = read_time(&cooldown).filter(|&until| until > now) {
+        let wait = until.duration_since(now).unwrap_or_default();
+        return Ok(Submission {
+            verdict: Verdict::RateLimited(wait),
+            sent: false,
+        });
+    }
+
+    let level = part.to_string();
+    let response = fetcher.post(
+        &format!("/{year}/day/{day}/answer"),
+        &[("level", &level), ("answer", answer)],
+    )?;
+    let verdict = Verdict::parse(&response.body).ok_or(FetchError::UnrecognisedResponse {
+        status: response.status,
+    })?;
+
+    let wait = match verdict {
+        Verdict::RateLimited(wait) => Some(wait),
+        Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong => wrong_answer_wait(&response.body),
+        _ => None,
+    };
+    if let Some(wait) = wait {
+        fs::create_dir_all(&account_dir)
+            .and_then(|()| write_time(&cooldown, SystemTime::now() + wait))
+            .map_err(|source| FetchError::Cache {
+                path: cooldown.clone(),
+                source,
+            })?;
+    }
+    if !matches!(verdict, Verdict::RateLimited(_)) {
+        guesses
+            .record(answer, verdict)
+            .map_err(|source| FetchError::Cache {
+                path: guesses.path.clone(),
+                source,
+            })?;
+    }
+
+    Ok(Submission {
+        verdict,
+        sent: true,
+    })
+}
+
+fn read_time(path: &Path) -> Option<SystemTime> {
+    let millis = fs::read_to_string(path).ok()?.trim().parse().ok()?;
+    Some(UNIX_EPOCH + Duration::from_millis(millis))
+}
+
+fn write_time(path: &Path, time: SystemTime) -> std::io::Result<()> {
+    let millis = time
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis();
+    fs::write(path, millis.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+    use crate::client::UreqClient;
+    use crate::stub_server::StubServer;
+
+    const TOO_HIGH: &str = "<article><p>That's not the right answer; your answer is too high. \
+        If you're stuck, make sure you're using the full input data. Please wait one minute \
+        before trying again.</p></article>";
+
+    fn fetcher(server: &StubServer, name: &str) -> Fetcher<UreqClient> {
+        let cache = env::temp_dir().join(format!("aoc-submit-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&cache);
+        Fetcher::new(
+            UreqClient::new(),
+            &server.base_url,
+            Some(String::from("secret")),
+            cache,
+        )
+        .with_min_interval(Duration::ZERO)
+    }
+
+    #[test]
+    fn parse_reads_each_verdict() {
+        let cases = [
+            (
+                "That's the right answer! You are one gold star",
+                Verdict::Correct,
+            ),
+            (TOO_HIGH, Verdict::TooHigh),
+            (
+                "That's not the right answer; your answer is too low.",
+                Verdict::TooLow,
+            ),
+            ("That's not the right answer.", Verdict::Wrong),
+            (
+                "You don't seem to be solving the right level.  Did you already complete it?",
+                Verdict::AlreadySolved,
+            ),
+            (
+                "You gave an answer too recently; you have to wait after submitting an answer \
+                 before trying again.  You have 1m 39s left to wait.",
+                Verdict::RateLimited(Duration::from_secs(99)),
+            ),
+        ];
+
+        for (body, expected) in cases {
+            assert_eq!(Verdict::parse(body), Some(expected), "{body}");
+        }
+    }
+
+    #[test]
+    fn parse_when_unknown_page_returns_none() {
+        assert_eq!(Verdict::parse("<html>Advent of Code</html>"), None);
+    }
+
+    #[test]
+    fn wrong_answer_wait_reads_minutes() {
+        assert_eq!(wrong_answer_wait(TOO_HIGH), Some(Duration::from_secs(60)));
+        assert_eq!(
+            wrong_answer_wait("please wait 5 minutes before trying again."),
+            Some(Duration::from_secs(300))
+        );
+    }
+
+    #[test]
+    fn submit_posts_level_and_answer() {
+        let server = StubServer::start(vec![(200, "That's the right answer!")]);
+        let fetcher = fetcher(&server, "posts");
+
+        let result = submit(&fetcher, 2022, 9, 1, "6391").unwrap();
+
+        assert_eq!(
+            result,
+            Submission {
+                verdict: Verdict::Correct,
+                sent: true
+            }
+        );
+        let requests = server.requests();
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].path, "/2022/day/9/answer");
+        assert_eq!(requests[0].body, "level=1&answer=6391");
+        fs::remove_dir_all(fetcher.cache_dir()).unwrap();
+    }
+
+    #[test]
+    fn submit_when_guessed_before_does_not_send_again() {
+        let server = StubServer::start(vec![(200, TOO_HIGH)]);
+        let fetcher = fetcher(&server, "repeat");
+
+        submit(&fetcher, 2022, 1, 2, "300000").unwrap();
+        fs::remove_file(fetcher.account_dir().join("cooldown-until")).unwrap();
+        let second = submit(&fetcher, 2022, 1, 2, "300000").unwrap();
+
+        assert_eq!(
+            second,
+            Submission {
+                verdict: Verdict::TooHigh,
+                sent: false
+            }
+        );
+        assert_eq!(server.requests().len(), 1);
+        fs::remove_dir_all(fetcher.cache_dir()).unwrap();
+    }
+
+    #[test]
+    fn submit_during_cooldown_does_not_send() {
+        let server = StubServer::start(vec![(200, TOO_HIGH)]);
+        let fetcher = fetcher(&server, "cooldown");
+
+        submit(&fetcher, 2022, 1, 2, "300000").unwrap();
+        let second = submit(&fetcher, 2022, 1, 2, "250000").unwrap();
+
+        assert!(matches!(second.verdict, Verdict::RateLimited(_)));
+        assert!(!second.sent);
+        assert_eq!(server.requests().len(), 1);
+        fs::remove_dir_all(fetcher.cache_dir()).unwrap();
+    }
+
+    #[test]
+    fn submit_against_other_base_url_does_not_share_guesses() {
+        let real = StubServer::start(vec![(200, "That's not the right answer.")]);
+        let stub = StubServer::start(vec![(200, "That's the right answer!")]);
+        let cache = env::temp_dir().join(format!("aoc-submit-hosts-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&cache);
+        let fetcher = |server: &StubServer| {
+            Fetcher::new(
+                UreqClient::new(),
+                &server.base_url,
+                Some(String::from("secret")),
+                cache.clone(),
+            )
+            .with_min_interval(Duration::ZERO)
+        };
+
+        submit(&fetcher(&real), 2022, 1, 1, "24000").unwrap();
+        let other = submit(&fetcher(&stub), 2022, 1, 1, "24000").unwrap();
+
+        assert_eq!(
+            other,
+            Submission {
+                verdict: Verdict::Correct,
+                sent: true
+            }
+        );
+        assert_eq!(stub.requests().len(), 1);
+        fs::remove_dir_all(cache).unwrap();
+    }
+}
@@ -0,0 +1,219 @@
+use std::fmt;
+use std::path::Path;
+
+use crate::answers::Answers;
+use crate::registry::Entry;
+use crate::runner;
+
+/// Input files every day is checked against, relative to the day's directory.
+pub const INPUT_FILES: &[&str] = &["input_test.txt", "input.txt"];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    /// The answer matches the recorded one.
+    Ok,
+    /// The answer differs from the recorded one.
+    Mismatch { expected: String },
+    /// The part is solved but no answer is recorded for it yet.
+    New,
+    /// An answer is recorded but the part or its input no longer exists.
+    Missing,
+    /// Reading or solving the input failed.
+    Failed(String),
+}
+
+impl Status {
+    pub fn is_failure(&self) -> bool {
+        matches!(
+            self,
+            Status::Mismatch { .. } | Status::Missing | Status::Failed(_)
+        )
+    }
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Ok => write!(f, "ok"),
+            Status::Mismatch { expected } => write!(f, "MISMATCH, expected {expected}"),
+            Status::New => write!(f, "new, no answer recorded"),
+            Status::Missing => write!(f, "MISSING"),
+            Status::Failed(error) => write!(f, "FAILED: {error}"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Outcome {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub input: String,
+    pub answer: Option<String>,
+    pub status: Status,
+}
+
+/// Runs every entry against each of its input files found under `root` and compares the
+/// results with `answers`.
+pub fn verify(entries: &[Entry], answers: &Answers, root: &Path) -> Vec<Outcome> {
+    let mut outcomes = Vec::new();
+
+    for entry in entries {
+        for &input in INPUT_FILES {
+            let expected = answers.get(entry.year, entry.day, entry.part, input);
+            let path = root.join(entry.dir).join(input);
+            if !path.exists() {
+                if expected.is_some() {
+                    outcomes.push(missing(entry.year, entry.day, entry.part, input));
+                }
+                continue;
+            }
+
+            let (answer, status) = match runner::solve(entry, &path) {
+                Ok(answer) => {
+                    let status = match expected {
+                        Some(expected) if expected == answer => Status::Ok,
+                        Some(expected) => Status::Mismatch {
+                            expected: String::from(expected),
+                        },
+                        None => Status::New,
+                    };
+                    (Some(answer), status)
+                }
+                Err(error) => (None, Status::Failed(error.to_string())),
+            };
+
+            outcomes.push(Outcome {
+                year: entry.year,
+                day: entry.day,
+                part: entry.part,
+                input: String::from(input),
+                answer,
+                status,
+            });
+        }
+    }
+
+    for recorded in answers.iter() {
+        let registered = entries.iter().any(|entry| {
+            entry.year == recorded.year && entry.day == recorded.day && entry.part == recorded.part
+        });
+        if !registered {
+            outcomes.push(missing(
+                recorded.year,
+                recorded.day,
+                recorded.part,
+                &recorded.input,
+            ));
+        }
+    }
+
+    outcomes
+}
+
+fn missing(year: u16, day: u8, part: u8, input: &str) -> Outcome {
+    Outcome {
+        year,
+        day,
+        part,
+        input: String::from(input),
+        answer: None,
+        status: Status::Missing,
+    }
+}
+
+pub fn print_table(outcomes: &[Outcome]) {
+    let answer_width = outcomes
+        .iter()
+        .filter_map(|outcome| outcome.answer.as_ref())
+        .map(|answer| answer.len())
+        .chain(["answer".len()])
+        .max()
+        .unwrap_or_default();
+
+    println!(
+        "{:<4}  {:<3}  {:<4}  {:<14}  {:<answer_width$}  status",
+        "year", "day", "part", "input", "answer"
+    );
+    for outcome in outcomes {
+        println!(
+            "{:<4}  {:<3}  {:<4}  {:<14}  {:<answer_width$}  {}",
+            outcome.year,
+            format!("{:02}", outcome.day),
+            outcome.part,
+            outcome.input,
+            outcome.answer.as_deref().unwrap_or("-"),
+            outcome.status
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs};
+
+    use aoc_common::Error;
+
+    use super::*;
+
+    fn echo(input: &str) -> Result<String, Error> {
+        Ok(String::from(input.trim()))
+    }
+
+    const ENTRIES: &[Entry] = &[Entry {
+        year: 2022,
+        day: 1,
+        part: 1,
+        dir: "day01",
+        solve: echo,
+        time: |_| unreachable!("verify does not time entries"),
+    }];
+
+    fn root_with_inputs(name: &str, test_input: &str, input: &str) -> std::path::PathBuf {
+        let root = env::temp_dir().join(format!("aoc-verify-{name}-{}", std::process::id()));
+        fs::create_dir_all(root.join("day01")).unwrap();
+        fs::write(root.join("day01/input_test.txt"), test_input).unwrap();
+        fs::write(root.join("day01/input.txt"), input).unwrap();
+        root
+    }
+
+    #[test]
+    fn verify_reports_ok_and_mismatch() {
+        let root = root_with_inputs("mismatch", "7", "42");
+        let answers = Answers::parse("2022 1 1 input_test.txt 7\n2022 1 1 input.txt 41").unwrap();
+
+        let outcomes = verify(ENTRIES, &answers, &root);
+
+        assert_eq!(outcomes[0].status, Status::Ok);
+        assert_eq!(
+            outcomes[1].status,
+            Status::Mismatch {
+                expected: String::from("41")
+            }
+        );
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn verify_when_no_answer_recorded_reports_new() {
+        let root = root_with_inputs("new", "7", "42");
+
+        let outcomes = verify(ENTRIES, &Answers::default(), &root);
+
+        assert!(outcomes.iter().all(|outcome| outcome.status == Status::New));
+        assert!(!outcomes[0].status.is_failure());
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn verify_when_part_not_registered_reports_missing() {
+        let root = root_with_inputs("missing", "7", "42");
+        let answers = Answers::parse("2022 1 2 input.txt 99").unwrap();
+
+        let outcomes = verify(ENTRIES, &answers, &root);
+
+        let last = outcomes.last().unwrap();
+        assert_eq!((last.part, &last.status), (2, &Status::Missing));
+        fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,19 @@
+use std::process::Command;
+
+#[test]
+fn run_all_prints_a_row_per_part() {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["run", "--all", "--test", "--jobs", "4"])
+        .output()
+        .expect("aoc binary runs");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        output.status.success(),
+        "aoc run --all failed:\n{stdout}{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(stdout.starts_with("year  day  part  answer"));
+    assert!(stdout.contains("2022  05   1     CMZ"));
+    assert!(stdout.contains("2022  09   1     13"));
+}
@@ -0,0 +1,16 @@
+use std::process::Command;
+
+#[test]
+fn recorded_answers_still_match() {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .arg("verify")
+        .output()
+        .expect("aoc binary runs");
+
+    assert!(
+        output.status.success(),
+        "aoc verify failed:\n{}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+}